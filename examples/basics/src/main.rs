@@ -11,10 +11,13 @@ enum Msg {
     ToggleTooltip,
     ShowTooltip,
     HideTooltip,
+    ShowModal,
+    HideModal,
 }
 
 struct Model {
     tooltip_show: bool,
+    modal_show: bool,
 }
 
 impl Component for Model {
@@ -24,6 +27,7 @@ impl Component for Model {
     fn create(_ctx: &Context<Self>) -> Self {
         Self {
             tooltip_show: false,
+            modal_show: false,
         }
     }
 
@@ -38,6 +42,12 @@ impl Component for Model {
             Msg::HideTooltip => {
                 self.tooltip_show = false;
             }
+            Msg::ShowModal => {
+                self.modal_show = true;
+            }
+            Msg::HideModal => {
+                self.modal_show = false;
+            }
         }
         true
     }
@@ -65,12 +75,13 @@ impl Component for Model {
 
         let tooltip_click_p_ref = NodeRef::default();
         let tooltip_link_ref = NodeRef::default();
+        let popover_ref = NodeRef::default();
 
         html! {
             <>
                 {include_inline()}
                 {BIFiles::cdn()}
-                <NavBar nav_id={"test-nav"} class="navbar-expand-lg navbar-light bg-light" brand={brand}>
+                <NavBar nav_id={"test-nav"} class="navbar-light bg-light" expand_at={ContainerSize::Large} brand={brand}>
                     <NavItem text="link 1" icon={&BI::EMOJI_SUNGLASSES} onclick={onclick.clone()} url="#" />
                     <NavItem text="link 2" onclick={onclick.clone()} url="#" />
                     <NavDropdown text="several items" icon={&BI::MENU_APP}>
@@ -90,6 +101,15 @@ impl Component for Model {
                         <Button class="btn-primary">{"Save changes"}</Button>
                     </ModalFooter>
                 </Modal>
+                <Modal show={self.modal_show} on_close={ctx.link().callback(|_| Msg::HideModal)} backdrop={ModalBackdrop::Static}>
+                    <ModalHeader title="Modal controlled by Yew" />
+                    <ModalBody>
+                        <p>{"This modal doesn't need Bootstrap's JavaScript. Its backdrop is static."}</p>
+                    </ModalBody>
+                    <ModalFooter>
+                        <Button style={Color::Secondary} modal_dismiss={true}>{"Close"}</Button>
+                    </ModalFooter>
+                </Modal>
                 <div id="layout" class="p-3">
                     <h1>{ "Accordion" }</h1>
                     <Accordion>
@@ -101,6 +121,15 @@ impl Component for Model {
                             <button>{"Button with some functionality"}</button>
                         </AccordionItem>
                     </Accordion>
+                    <h2>{ "Always open, with Html in the heading" }</h2>
+                    <Accordion id="accordion-stay-open" stay_open={true}>
+                        <AccordionItem header={html! { <>{BI::INBOX}{" Inbox "}<Badge style={Color::Primary}>{"4"}</Badge></> }} expanded={true}>
+                            <p>{"Opening another item doesn't close this one."}</p>
+                        </AccordionItem>
+                        <AccordionItem title={"Archive"}>
+                            <p>{"Archived messages."}</p>
+                        </AccordionItem>
+                    </Accordion>
                     <h1>{ "Containers" }</h1>
                     <Container class="bg-primary">{"Normal"}</Container>
                     <Container class="bg-secondary" fluid={true}>{"Fluid"}</Container>
@@ -257,8 +286,102 @@ impl Component for Model {
                         {"This is a lead paragraph. It stands out from regular paragraphs."}
                     </Lead>
 
+                    <h1>{"Toasts"}</h1>
+                    <ToastProvider>
+                        <ToastDemo />
+                    </ToastProvider>
+
                     <h1>{"Modals"}</h1>
                     <Button style={Color::Primary} modal_target={"ExampleModal"}>{"Open Modal"}</Button>
+                    <Button style={Color::Primary} onclick={ctx.link().callback(|_| Msg::ShowModal)}>{"Open Modal controlled by Yew"}</Button>
+
+                    <h1>{"Carousel"}</h1>
+                    <Carousel indicators={true} autoplay={true} prev_icon={&BI::CHEVRON_LEFT} next_icon={&BI::CHEVRON_RIGHT}>
+                        {
+                            for [("First", "bg-primary"), ("Second", "bg-success"), ("Third", "bg-danger")].into_iter().map(|(title, background)| {
+                                html_nested! {
+                                    <CarouselItem>
+                                        <div class={classes!("d-block", "w-100", background)} style="height: 300px;"></div>
+                                        <CarouselCaption><h5>{format!("{title} slide")}</h5></CarouselCaption>
+                                    </CarouselItem>
+                                }
+                            })
+                        }
+                    </Carousel>
+
+                    <h1>{"Collapse"}</h1>
+                    <CollapseDemo />
+
+                    <h1>{"Dropdowns"}</h1>
+                    <Dropdown class="d-inline-block me-2">
+                        <DropdownToggle style={Color::Secondary} text="Dropdown" />
+                        <DropdownMenu>
+                            <DropdownHeader text="Header" />
+                            <DropdownItem text="Action" icon={&BI::CHECK_CIRCLE} onclick={Callback::from(|_| debug!("Dropdown action"))} />
+                            <DropdownItem text="Active" active={true} />
+                            <DropdownDivider />
+                            <DropdownItem text="Disabled" disabled={true} />
+                        </DropdownMenu>
+                    </Dropdown>
+                    <Dropdown group={true} direction={DropDirection::Up} auto_close={DropdownAutoClose::Outside}>
+                        <DropdownToggle style={Color::Danger} text="Split dropup" split={true} />
+                        <DropdownMenu dark={true}>
+                            <DropdownItem text="Stays open when clicked" />
+                        </DropdownMenu>
+                    </Dropdown>
+
+                    <h1>{"Navs"}</h1>
+                    <Nav variant={NavVariant::Pills}>
+                        <NavItem text="Active" url="#" active={true} />
+                        <NavItem text="Link" url="#" />
+                        <NavItem text="Disabled" disabled={true} />
+                    </Nav>
+
+                    <h1>{"Tabs"}</h1>
+                    <Tabs id="example-tabs">
+                        <TabPane event_key="home" title="Home" icon={&BI::HOUSE}>
+                            <p class="p-3">{"Panes are switched by Yew, with a fade transition."}</p>
+                        </TabPane>
+                        <TabPane event_key="profile" title="Profile">
+                            <p class="p-3">{"Content of the profile pane."}</p>
+                        </TabPane>
+                        <TabPane event_key="disabled" title="Disabled" disabled={true} />
+                    </Tabs>
+
+                    <h1>{"Breadcrumb"}</h1>
+                    <Breadcrumb>
+                        <BreadcrumbItem text="Home" url="#" icon={&BI::HOUSE} />
+                        <BreadcrumbItem text="Library" url="#" />
+                        <BreadcrumbItem text="Data" active={true} />
+                    </Breadcrumb>
+                    <Breadcrumb divider={BreadcrumbDivider::Text(">".into())}>
+                        <BreadcrumbItem text="Home" url="#" />
+                        <BreadcrumbItem text="Library" active={true} />
+                    </Breadcrumb>
+
+                    <h1>{"Pagination"}</h1>
+                    <PaginationDemo />
+
+                    <h1>{"Table"}</h1>
+                    <TableDemo />
+
+                    <h1>{"Offcanvas"}</h1>
+                    <OffcanvasDemo />
+
+                    <h1>{"Placeholders"}</h1>
+                    <PlaceholderGlow>
+                        <Placeholder width={6} />
+                        <Placeholder width={4} size={PlaceholderSize::Large} style={Color::Primary} />
+                    </PlaceholderGlow>
+                    <PlaceholderText animation={PlaceholderAnimation::Wave} />
+                    <Row>
+                        <Column md=6>
+                            <PlaceholderCard image_height="120px" button={Color::Primary} />
+                        </Column>
+                        <Column md=6>
+                            <PlaceholderListGroup items={4} />
+                        </Column>
+                    </Row>
 
                     <h1>{"Buttons"}</h1>
                     <Button style={Color::Primary}>{"Primary"}</Button>
@@ -379,6 +502,12 @@ impl Component for Model {
                     <h2>{"Animated"}</h2>
                     <Progress class={"mb-3"}><ProgressBar value=25 animated={true}/></Progress>
 
+                    <h1 id="popover">{"Popover"}</h1>
+                    <Button style={Color::Danger} node_ref={popover_ref.clone()}>{"Click to toggle popover"}</Button>
+                    <Popover target={popover_ref} title="Popover title" id="example-popover">
+                        {"And here's some "}<strong>{"amazing"}</strong>{" content. Click outside to dismiss it."}
+                    </Popover>
+
                     <h1 id="tooltip">{"Tooltip"}</h1>
                     <p>
                         {"The "}
@@ -545,6 +674,212 @@ impl Component for Model {
     }
 }
 
+/// Buttons pushing toasts to the [ToastProvider]
+#[function_component]
+fn ToastDemo() -> Html {
+    let toaster = use_toast();
+
+    html! {
+        <ButtonGroup>
+            {
+                for [
+                    (Color::Success, &BI::CHECK_CIRCLE, "Saved"),
+                    (Color::Danger, &BI::EXCLAMATION_TRIANGLE, "Error"),
+                    (Color::Info, &BI::INFO_CIRCLE, "Information"),
+                ].into_iter().map(|(color, icon, title)| {
+                    let toaster = toaster.clone();
+                    let style = color.clone();
+                    let onclick = Callback::from(move |_| {
+                        toaster.push(ToastOptions {
+                            title: Some(title.into()),
+                            body: html! { {format!("This is a {color} toast, closing after 5 seconds.")} },
+                            icon: Some(icon),
+                            ..ToastOptions::default()
+                        });
+                    });
+                    html_nested! { <Button {style} {onclick} text={title} /> }
+                })
+            }
+            <Button style={Color::Dark} onclick={
+                let toaster = toaster.clone();
+                Callback::from(move |_| {
+                    toaster.push(ToastOptions {
+                        body: html! { "A colored toast, which must be dismissed." },
+                        color: Some(Color::Dark),
+                        delay: None,
+                        ..ToastOptions::default()
+                    });
+                })
+            }>{"Colored"}</Button>
+        </ButtonGroup>
+    }
+}
+
+/// Buttons toggling a vertical and a horizontal [Collapse]
+#[function_component]
+fn CollapseDemo() -> Html {
+    let vertical = use_collapse("collapse-vertical", false);
+    let horizontal = use_collapse("collapse-horizontal", false);
+
+    html! {
+        <>
+            <p>
+                <button
+                    class="btn btn-primary me-1"
+                    type="button"
+                    onclick={vertical.toggle.clone()}
+                    aria-expanded={vertical.aria_expanded()}
+                    aria-controls={vertical.id.clone()}
+                >
+                    {"Toggle vertical collapse"}
+                </button>
+                <button
+                    class="btn btn-primary"
+                    type="button"
+                    onclick={horizontal.toggle.clone()}
+                    aria-expanded={horizontal.aria_expanded()}
+                    aria-controls={horizontal.id.clone()}
+                >
+                    {"Toggle horizontal collapse"}
+                </button>
+            </p>
+            <Collapse id={vertical.id.clone()} open={vertical.open}>
+                <div class="card card-body mb-3">{"This content is shown and hidden by Yew, animating its height."}</div>
+            </Collapse>
+            <div style="min-height: 120px;">
+                <Collapse id={horizontal.id.clone()} open={horizontal.open} horizontal={true}>
+                    <div class="card card-body" style="width: 300px;">{"This content animates its width."}</div>
+                </Collapse>
+            </div>
+        </>
+    }
+}
+
+/// [Pagination] through 20 pages, with the default and a larger window
+#[function_component]
+fn PaginationDemo() -> Html {
+    let page = use_state_eq(|| 1);
+    let on_page_change = {
+        let page = page.clone();
+        Callback::from(move |p| page.set(p))
+    };
+
+    html! {
+        <>
+            <Pagination total_pages={20} current={*page} on_page_change={on_page_change.clone()} />
+            <Pagination
+                total_pages={20}
+                current={*page}
+                {on_page_change}
+                siblings={2}
+                size={PaginationSize::Small}
+                alignment={PaginationAlignment::Center}
+            />
+        </>
+    }
+}
+
+/// Buttons opening an [Offcanvas] from each side of the viewport
+#[function_component]
+fn OffcanvasDemo() -> Html {
+    let placement = use_state_eq(|| None::<OffcanvasPlacement>);
+    let last_placement = use_state_eq(OffcanvasPlacement::default);
+
+    let on_hide = {
+        let placement = placement.clone();
+        Callback::from(move |_| placement.set(None))
+    };
+
+    html! {
+        <>
+            <ButtonGroup>
+                {
+                    for [
+                        (OffcanvasPlacement::Start, "Start"),
+                        (OffcanvasPlacement::End, "End"),
+                        (OffcanvasPlacement::Top, "Top"),
+                        (OffcanvasPlacement::Bottom, "Bottom"),
+                    ].into_iter().map(|(side, title)| {
+                        let placement = placement.clone();
+                        let last_placement = last_placement.clone();
+                        let onclick = Callback::from(move |_| {
+                            placement.set(Some(side));
+                            last_placement.set(side);
+                        });
+                        html_nested! { <Button style={Color::Primary} {onclick} text={title} /> }
+                    })
+                }
+            </ButtonGroup>
+            <Offcanvas show={placement.is_some()} {on_hide} placement={*last_placement}>
+                <OffcanvasHeader title="Offcanvas" />
+                <OffcanvasBody>
+                    <p>{"This offcanvas doesn't need Bootstrap's JavaScript."}</p>
+                    <p>{"Close it with the button, the Escape key or by clicking on the backdrop."}</p>
+                </OffcanvasBody>
+            </Offcanvas>
+        </>
+    }
+}
+
+/// Row of the [TableDemo]
+#[derive(Clone, PartialEq)]
+struct Planet {
+    name: &'static str,
+    moons: u32,
+    radius: f64,
+}
+
+/// Sortable, selectable and paginated [Table] of planets
+#[function_component]
+fn TableDemo() -> Html {
+    let selected = use_state_eq(Vec::<usize>::new);
+    let planets = vec![
+        Planet { name: "Mercury", moons: 0, radius: 2439.7 },
+        Planet { name: "Venus", moons: 0, radius: 6051.8 },
+        Planet { name: "Earth", moons: 1, radius: 6371.0 },
+        Planet { name: "Mars", moons: 2, radius: 3389.5 },
+        Planet { name: "Jupiter", moons: 95, radius: 69911.0 },
+        Planet { name: "Saturn", moons: 146, radius: 58232.0 },
+        Planet { name: "Uranus", moons: 28, radius: 25362.0 },
+        Planet { name: "Neptune", moons: 16, radius: 24622.0 },
+    ];
+    let columns = vec![
+        TableColumn::new("Name", |planet: &Planet| html! { planet.name })
+            .sort_by_key(|planet| planet.name),
+        TableColumn::new("Moons", |planet: &Planet| html! { planet.moons })
+            .sort_by_key(|planet| planet.moons)
+            .class("text-end"),
+        TableColumn::new("Radius (km)", |planet: &Planet| html! { format!("{:.1}", planet.radius) })
+            .sort_by(|a, b| a.radius.total_cmp(&b.radius))
+            .class("text-end"),
+    ];
+
+    let on_select = {
+        let selected = selected.clone();
+        Callback::from(move |indices| selected.set(indices))
+    };
+    let on_row_click = Callback::from(|planet: Planet| debug!("Clicked on", planet.name));
+
+    html! {
+        <>
+            <Table<Planet>
+                rows={planets}
+                {columns}
+                striped={true}
+                hover={true}
+                small={true}
+                responsive={ContainerSize::ExtraSmall}
+                selectable={true}
+                selected={(*selected).clone()}
+                {on_select}
+                {on_row_click}
+                page_size={5}
+            />
+            <p>{ format!("{} planet(s) selected", selected.len()) }</p>
+        </>
+    }
+}
+
 fn main() {
     yew::Renderer::<Model>::new().render();
 }
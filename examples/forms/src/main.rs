@@ -8,6 +8,18 @@ use yew_bootstrap::icons::*;
 use yew_bootstrap::util::*;
 use yew_bootstrap::component::form::*;
 
+mod sign_up;
+use sign_up::SignUp;
+
+mod profile;
+use profile::ProfileForm;
+
+mod typed_callbacks;
+use typed_callbacks::TypedCallbacks;
+
+mod price_filter;
+use price_filter::PriceFilter;
+
 #[derive(Debug)]
 enum Msg {
     None,
@@ -177,7 +189,7 @@ impl Component for Model {
             <>
                 {include_inline()}
                 {BIFiles::cdn()}
-                <NavBar nav_id={"test-nav"} class="navbar-expand-lg navbar-light bg-light" brand={brand}>
+                <NavBar nav_id={"test-nav"} class="navbar-light bg-light" expand_at={ContainerSize::Large} brand={brand}>
                     <NavItem text="link 1" />
                     <NavItem text="link 2" />
                     <NavDropdown text="several items">
@@ -227,6 +239,25 @@ impl Component for Model {
                             ctype={ FormControlType::Range { min: -10, max: 10, step: Some(5)} }
                             class="mb-3" label="Range -10-10, step 5" value="-5"
                         />
+                        <FormControl
+                            id="input-decimal"
+                            ctype={ FormControlType::Decimal { min: Some(-1.5), max: Some(1.5), step: Some(0.1) } }
+                            class="mb-3"
+                            label="Decimal number in range -1.5-1.5, step 0.1"
+                            value="0.3"
+                        />
+                        <FormControl
+                            id="input-decimal-range"
+                            ctype={ FormControlType::DecimalRange {
+                                min: 0.0,
+                                max: 1.0,
+                                step: Some(0.05),
+                                ticks: vec![0.0, 0.25, 0.5, 0.75, 1.0],
+                                show_value: true,
+                            } }
+                            class="mb-3"
+                            label="Decimal range 0-1, step 0.05, with ticks and value"
+                        />
                         <FormControl
                             id="input-select1"
                             ctype={ FormControlType::Select}
@@ -549,6 +580,41 @@ impl Component for Model {
                             <SelectOption key=3 label="Option 3" value="3"/>
                         </FormControl>
                     </Container>
+                    <h2>{ "Input groups" }</h2>
+                    <Container size={ContainerSize::ExtraLarge}>
+                        <InputGroup class="mb-3">
+                            <InputGroupText>{ "@" }</InputGroupText>
+                            <FormControl id="input-group-username" ctype={ FormControlType::Text } label="Username" placeholder="Username" />
+                        </InputGroup>
+                        <InputGroup class="mb-3">
+                            <FormControl id="input-group-recipient" ctype={ FormControlType::Text } label="Recipient's username" placeholder="Recipient's username" />
+                            <InputGroupText>{ "@example.com" }</InputGroupText>
+                            <Button style={ Color::Secondary } outline=true>{ "Button" }</Button>
+                        </InputGroup>
+                        <InputGroup class="mb-3" size={ InputGroupSize::Small }>
+                            <FormControl id="input-group-checkbox" ctype={ FormControlType::Checkbox } label="Checkbox for following text input" />
+                            <FormControl id="input-group-amount" ctype={ FormControlType::Text } label="Amount" />
+                            <Dropdown>
+                                <DropdownToggle style={ Color::Secondary } outline=true text="Currency" />
+                                <DropdownMenu align_end=true>
+                                    <DropdownItem text="EUR" />
+                                    <DropdownItem text="USD" />
+                                </DropdownMenu>
+                            </Dropdown>
+                        </InputGroup>
+                        <InputGroup class="mb-3">
+                            <InputGroupText>{ BI::HASH }</InputGroupText>
+                            <FormControl
+                                id="input-group-validation"
+                                ctype={ FormControlType::Number { min: None, max: None } }
+                                label="Number in range 10-20"
+                                value={ self.number_value.clone() }
+                                oninput={ oninput.clone() }
+                                validation={ self.number_feedback.clone() }
+                            />
+                            <InputGroupText>{ "10-20" }</InputGroupText>
+                        </InputGroup>
+                    </Container>
                     <h2>{ "Form validation" }</h2>
                     <p>{
                         "Set feedback message to report a valid or invalid field. This sets the is-valid or is-invalid class"
@@ -572,6 +638,22 @@ impl Component for Model {
                             validation={ self.number_feedback.clone() }
                         />
                     </Container>
+                    <h2>{ "Form context" }</h2>
+                    <p>{
+                        "A Form tracks the values of its named fields and checks their validators when leaving
+                         them and when submitting. The values are only received when all fields are valid."
+                    }</p>
+                    <Container size={ContainerSize::ExtraLarge}>
+                        <SignUp />
+                    </Container>
+                    <h2>{ "Form from a struct" }</h2>
+                    <p>{
+                        "With the derive feature, a struct deriving BootstrapForm is edited by a StructForm, with a
+                         control for each field chosen from its type. The struct is parsed back on submit."
+                    }</p>
+                    <Container size={ContainerSize::ExtraLarge}>
+                        <ProfileForm />
+                    </Container>
                     <h2>{ "Form events"}</h2>
                     <p>{ "Several events can be used" }</p>
                     <ul>
@@ -699,6 +781,21 @@ impl Component for Model {
                             }).collect::<Html>()
                         }
                     </Container>
+                    <h2>{ "Typed callbacks" }</h2>
+                    <p>{
+                        "Typed callbacks receive the value directly: on_value, on_checked, on_number, on_files and on_selected."
+                    }</p>
+                    <Container size={ContainerSize::ExtraLarge}>
+                        <TypedCallbacks />
+                    </Container>
+                    <h2>{ "Range slider" }</h2>
+                    <p>{
+                        "A RangeSlider selects a range with two thumbs, moved with the pointer or the keyboard. Inside a Form,
+                         its value is validated like the other fields."
+                    }</p>
+                    <Container size={ContainerSize::ExtraLarge}>
+                        <PriceFilter />
+                    </Container>
                 </div>
                 { include_cdn_js() }
             </>
@@ -0,0 +1,59 @@
+use yew::prelude::*;
+use yew_bootstrap::component::*;
+use yew_bootstrap::component::form::*;
+use yew_bootstrap::util::*;
+
+/// Range sliders selecting a price range, alone and inside a [Form]
+#[function_component]
+pub fn PriceFilter() -> Html {
+    let price = use_state(|| (20.0, 80.0));
+    let submitted = use_state(|| None::<(f64, f64)>);
+
+    let on_change = {
+        let price = price.clone();
+        Callback::from(move |range: (f64, f64)| price.set(range))
+    };
+    let on_submit = {
+        let submitted = submitted.clone();
+        Callback::from(move |values: FormValues| submitted.set(parse_range_value(&values["budget"])))
+    };
+
+    let minimum_width = Validator::custom(|value| match parse_range_value(value) {
+        Some((low, high)) if high - low >= 100.0 => Ok(()),
+        _ => Err(AttrValue::from("Select a range of at least 100")),
+    });
+
+    html! {
+        <>
+            <RangeSlider
+                id="price-filter"
+                class="mb-3"
+                label="Price"
+                min={ 0.0 }
+                max={ 200.0 }
+                step={ 5.0 }
+                value={ *price }
+                {on_change}
+                show_value=true
+                help={ Some(AttrValue::from(format!("Showing items from {} to {}", price.0, price.1))) }
+            />
+            <Form {on_submit} trigger={ ValidationTrigger::Input } class="mb-3">
+                <RangeSlider
+                    id="budget"
+                    name="budget"
+                    class="mb-3"
+                    label="Budget, at least 100 wide"
+                    min={ -500.0 }
+                    max={ 500.0 }
+                    step={ 10.0 }
+                    show_value=true
+                    validators={ vec![minimum_width] }
+                />
+                <Button style={ Color::Primary }>{ "Save budget" }</Button>
+                if let Some((low, high)) = *submitted {
+                    <p class="mt-3">{ format!("Budget saved: {low} to {high}") }</p>
+                }
+            </Form>
+        </>
+    }
+}
@@ -0,0 +1,58 @@
+use yew::prelude::*;
+use yew_bootstrap::component::form::*;
+
+/// Plan selected in a [Profile], displayed as a select
+#[derive(FormValue, Clone, Debug, PartialEq)]
+pub enum Plan {
+    Free,
+    #[form(label = "Professional")]
+    Pro,
+    Enterprise,
+}
+
+/// Struct edited with a [StructForm], each field with a control chosen from its type
+#[derive(BootstrapForm, Clone, Debug, PartialEq)]
+pub struct Profile {
+    #[form(required, max_length = 16, autocomplete = FormAutocompleteType::Username)]
+    username: String,
+    #[form(label = "E-mail", required, pattern = "[^@]+@[^@]+", autocomplete = FormAutocompleteType::Email)]
+    email: String,
+    #[form(label = "Date of birth", date)]
+    birth_date: String,
+    #[form(help = "Optional, between 0 and 255")]
+    age: Option<u8>,
+    plan: Plan,
+    #[form(textarea, placeholder = "Tell us about yourself")]
+    about: String,
+    #[form(label = "Subscribe to the newsletter")]
+    newsletter: bool,
+}
+
+/// Form generated from the [Profile] struct
+#[function_component]
+pub fn ProfileForm() -> Html {
+    let submitted = use_state(|| None::<Profile>);
+
+    let on_submit = {
+        let submitted = submitted.clone();
+        Callback::from(move |profile: Profile| submitted.set(Some(profile)))
+    };
+
+    let value = Profile {
+        username: String::new(),
+        email: String::new(),
+        birth_date: String::new(),
+        age: None,
+        plan: Plan::Free,
+        about: String::new(),
+        newsletter: true,
+    };
+
+    html! {
+        <StructForm<Profile> {value} {on_submit} class="mb-3" submit_text="Save">
+            if let Some(profile) = &*submitted {
+                <pre class="mb-3">{ format!("{profile:#?}") }</pre>
+            }
+        </StructForm<Profile>>
+    }
+}
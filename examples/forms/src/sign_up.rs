@@ -0,0 +1,79 @@
+use yew::prelude::*;
+use yew_bootstrap::component::*;
+use yew_bootstrap::component::form::*;
+use yew_bootstrap::util::*;
+
+/// Form validated with a [Form] context
+///
+/// Fields register in the form by name, and their validators are checked when leaving
+/// them and when submitting. The values are only received when all fields are valid.
+#[function_component]
+pub fn SignUp() -> Html {
+    let submitted = use_state(|| None::<FormValues>);
+
+    let on_submit = {
+        let submitted = submitted.clone();
+        Callback::from(move |values: FormValues| submitted.set(Some(values)))
+    };
+
+    let password_strength = Validator::custom(|value| {
+        if value.chars().any(|c| c.is_ascii_digit()) {
+            Ok(())
+        } else {
+            Err(AttrValue::from("The password must contain a digit"))
+        }
+    });
+
+    html! {
+        <Form {on_submit} class="mb-3">
+            <FormControl
+                id="sign-up-username"
+                name="username"
+                ctype={ FormControlType::Text }
+                class="mb-3"
+                label="Username"
+                validators={ vec![
+                    Validator::Required("Choose a username".into()),
+                    Validator::MaxLength(16, "At most 16 characters".into()),
+                    Validator::Pattern("[a-z0-9_]+".into(), "Only lower case letters, digits and _".into()),
+                ] }
+            />
+            <FormControl
+                id="sign-up-password"
+                name="password"
+                ctype={ FormControlType::Password }
+                class="mb-3"
+                label="Password"
+                validators={ vec![
+                    Validator::Required("Choose a password".into()),
+                    Validator::MinLength(8, "At least 8 characters".into()),
+                    password_strength,
+                ] }
+            />
+            <FormControl
+                id="sign-up-plan"
+                name="plan"
+                ctype={ FormControlType::Select }
+                class="mb-3"
+                label="Plan"
+                validators={ vec![Validator::Required("Select a plan".into())] }
+            >
+                <SelectOption key=0 label="Select a plan" selected=true />
+                <SelectOption key=1 label="Free" value="free" />
+                <SelectOption key=2 label="Pro" value="pro" />
+            </FormControl>
+            <FormControl
+                id="sign-up-terms"
+                name="terms"
+                ctype={ FormControlType::Checkbox }
+                class="mb-3"
+                label="I accept the terms"
+                validators={ vec![Validator::Required("Accept the terms to continue".into())] }
+            />
+            <Button style={ Color::Primary }>{ "Sign up" }</Button>
+            if let Some(values) = &*submitted {
+                <p class="mt-3">{ format!("Signed up as {} with plan {}", values["username"], values["plan"]) }</p>
+            }
+        </Form>
+    }
+}
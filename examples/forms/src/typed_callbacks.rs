@@ -0,0 +1,90 @@
+use web_sys::File;
+use yew::prelude::*;
+use yew_bootstrap::component::form::*;
+
+/// Controls using the typed callbacks, receiving the values without converting
+/// the target of the events
+#[function_component]
+pub fn TypedCallbacks() -> Html {
+    let text = use_state(String::new);
+    let quantity = use_state(|| None::<f64>);
+    let checked = use_state(|| false);
+    let sizes = use_state(Vec::<String>::new);
+    let files = use_state(Vec::<File>::new);
+
+    let on_value = {
+        let text = text.clone();
+        Callback::from(move |value: String| text.set(value))
+    };
+    let on_number = {
+        let quantity = quantity.clone();
+        Callback::from(move |value: Option<f64>| quantity.set(value))
+    };
+    let on_checked = {
+        let checked = checked.clone();
+        Callback::from(move |value: bool| checked.set(value))
+    };
+    let on_selected = {
+        let sizes = sizes.clone();
+        Callback::from(move |values: Vec<String>| sizes.set(values))
+    };
+    let on_files = {
+        let files = files.clone();
+        Callback::from(move |values: Vec<File>| files.set(values))
+    };
+
+    let file_names = files.iter().map(File::name).collect::<Vec<_>>().join(", ");
+
+    html! {
+        <>
+            <FormControl
+                id="typed-text"
+                ctype={ FormControlType::Text }
+                class="mb-3"
+                label="Text with on_value"
+                value={ (*text).clone() }
+                {on_value}
+                help={ Some(AttrValue::from(format!("Current value: {}", *text))) }
+            />
+            <FormControl
+                id="typed-number"
+                ctype={ FormControlType::Number { min: Some(1), max: Some(100) } }
+                class="mb-3"
+                label="Number with on_number"
+                {on_number}
+                help={ Some(AttrValue::from(format!("Current value: {:?}", *quantity))) }
+            />
+            <FormControl
+                id="typed-checkbox"
+                ctype={ FormControlType::Checkbox }
+                class="mb-3"
+                label="Checkbox with on_checked"
+                checked={ *checked }
+                {on_checked}
+                help={ Some(AttrValue::from(format!("Current value: {}", *checked))) }
+            />
+            <FormControl
+                id="typed-select"
+                ctype={ FormControlType::Select }
+                class="mb-3"
+                label="Multiple select with on_selected"
+                multiple=true
+                {on_selected}
+                help={ Some(AttrValue::from(format!("Selected: {}", sizes.join(", ")))) }
+            >
+                <SelectOption key=0 label="Small" value="S" />
+                <SelectOption key=1 label="Medium" value="M" />
+                <SelectOption key=2 label="Large" value="L" />
+            </FormControl>
+            <FormControl
+                id="typed-files"
+                ctype={ FormControlType::File { accept: vec![] } }
+                class="mb-3"
+                label="Files with on_files"
+                multiple=true
+                {on_files}
+                help={ Some(AttrValue::from(format!("Selected files: {file_names}"))) }
+            />
+        </>
+    }
+}
@@ -1,6 +1,6 @@
 use std::rc::Rc;
 use yew::prelude::*;
-use yew_bootstrap::component::{Container, ContainerSize, SearchableSelect, SOption, filter_case, FilterFn};
+use yew_bootstrap::component::{Container, ContainerSize, SearchableSelect, SOption, filter_case, FilterFn, score_fuzzy};
 
 /// Properties for [Filter]
 #[derive(Properties, PartialEq)]
@@ -65,11 +65,26 @@ pub fn Filter(props: &FilterProps) -> Html {
                    sensitive)."}
             </p>
 
+            <SearchableSelect
+                options={options.clone()}
+                title={title.clone()}
+                placeholder="Select an option..."
+                filter={ filter_custom() }
+                onselectchange={onselectchange.clone()}
+            />
+
+            <p>
+                { "To sort options by relevance, use a " }<code>{ "ScoreFn" }</code>{ " in the "
+                }<code>{ "score" }</code>{ " property. With " }<code>{ "score_fuzzy()" }</code>{ ",
+                   options containing the typed characters in the same order are kept, ignoring case
+                   and accents, and the matched characters are highlighted. Try " }<code>{ "ery" }</code>{ "." }
+            </p>
+
             <SearchableSelect
                 options={options}
                 {title}
                 placeholder="Select an option..."
-                filter={ filter_custom() }
+                score={ score_fuzzy() }
                 onselectchange={onselectchange}
             />
         </Container>
@@ -0,0 +1,57 @@
+use yew::prelude::*;
+use yew_bootstrap::component::{Container, ContainerSize, SearchableSelect, SOption};
+
+/// Searchable select example with a large list of options
+///
+/// Shows the virtualized rendering of the list, where only the visible options are rendered
+#[function_component]
+pub fn Large() -> Html {
+    // Get value of selected item, if any
+    let selected = use_state(|| None::<u32>);
+
+    // Create 10000 options, with a header every 100 options
+    let options: Vec<SOption<u32>> = (0..10_000).map(|i| {
+        if i % 100 == 0 {
+            SOption {
+                header: true,
+                title: AttrValue::from(format!("Items {} to {}", i, i + 99)),
+                ..SOption::default()
+            }
+        } else {
+            SOption {
+                selected: Some(i) == *selected,
+                ..SOption::new(i, format!("Item {i}"))
+            }
+        }
+    }).collect();
+
+    let onselectchange = {
+        let selected = selected.clone();
+        Callback::from(move |(value, to_selected): (u32, bool)| {
+            if to_selected {
+                selected.set(Some(value))
+            }
+        })
+    };
+
+    let title = selected.map(|value| format!("Selected value: {value}"));
+
+    html! {
+        <Container class="mt-5" size={ ContainerSize::Small } >
+            <h2>{ "Searchable Select Demo - Large list" }</h2>
+            <p>
+                { "With the " }<code>{ "virtualize" }</code>{ " property, only the visible options are
+                   rendered, so that lists of thousands of options stay fast. All options have the
+                   same height, set with " }<code>{ "item_height" }</code>{ "." }
+            </p>
+
+            <SearchableSelect<u32>
+                {options}
+                {title}
+                placeholder="Select an item..."
+                {onselectchange}
+                virtualize={true}
+            />
+        </Container>
+    }
+}
@@ -6,12 +6,18 @@ mod multiple;
 mod headers;
 mod form;
 mod filter;
+mod remote;
+mod large;
+mod tags;
 
 use single::*;
 use multiple::*;
 use headers::*;
 use form::*;
 use filter::*;
+use remote::*;
+use large::*;
+use tags::*;
 
 #[function_component(App)]
 fn app() -> Html {
@@ -46,6 +52,9 @@ fn app() -> Html {
             <Headers items={items_with_headers.to_vec()} />
             <Form items={items.to_vec()} />
             <Filter items={items.to_vec()} />
+            <Remote />
+            <Large />
+            <Tags items={items.to_vec()} />
             <p style="height: 400px;"></p>
             { include_cdn_js() }
         </>
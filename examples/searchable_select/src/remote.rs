@@ -0,0 +1,58 @@
+use gloo_timers::future::TimeoutFuture;
+use yew::prelude::*;
+use yew_bootstrap::component::{Container, ContainerSize, LoaderFn, SearchableSelect, SOption};
+
+/// Simulated remote API, returning the numbers containing the searched text
+async fn search_numbers(search: AttrValue) -> Result<Vec<SOption<u32>>, AttrValue> {
+    TimeoutFuture::new(800).await;
+    if search.as_str() == "error" {
+        return Err(AttrValue::from("The server could not be reached"));
+    }
+    Ok((1..=10_000)
+        .filter(|number: &u32| number.to_string().contains(search.as_str()))
+        .take(50)
+        .map(|number| SOption::new(number, format!("Number {number}")))
+        .collect())
+}
+
+/// Remote searchable select example
+///
+/// Show how to load options asynchronously, with typed values
+#[function_component]
+pub fn Remote() -> Html {
+    let selected = use_state(|| None::<u32>);
+    let loader = use_memo((), |_| LoaderFn::from(search_numbers));
+
+    // Only the selected option is provided, to mark it in the loaded options
+    let options: Vec<SOption<u32>> = selected.iter().map(|number| SOption {
+        selected: true,
+        ..SOption::new(*number, "")
+    }).collect();
+
+    let onselectchange = {
+        let selected = selected.clone();
+        Callback::from(move |(number, to_selected): (u32, bool)| {
+            selected.set(to_selected.then_some(number))
+        })
+    };
+
+    let title = selected.map(|number| AttrValue::from(format!("Selected number: {number}")));
+
+    html! {
+        <Container class="mt-5" size={ ContainerSize::Small } >
+            <h2>{ "Searchable Select Demo - Remote options" }</h2>
+            <p>
+                { "Options are loaded by an asynchronous "}<code>{ "loader" }</code>{ " simulating a remote
+                   API, among 10000 numbers. Type "}<code>{ "error" }</code>{ " to simulate a failure." }
+            </p>
+
+            <SearchableSelect<u32>
+                {options}
+                {title}
+                placeholder="Search a number..."
+                loader={ (*loader).clone() }
+                {onselectchange}
+            />
+        </Container>
+    }
+}
@@ -0,0 +1,68 @@
+use yew::prelude::*;
+use yew_bootstrap::component::{Container, ContainerSize, SearchableSelect, SOption};
+
+/// Properties for [Tags]
+#[derive(Properties, PartialEq)]
+pub struct TagsProps {
+    /// Initial list of tags
+    pub items: Vec<AttrValue>,
+}
+
+/// Searchable select example used as a tag input
+///
+/// Shows the creation of options and the display of the selection as chips
+#[function_component]
+pub fn Tags(props: &TagsProps) -> Html {
+    // List of tags, with their selection
+    let tags = {
+        let items = props.items.clone();
+        use_state(move || items.into_iter().map(|item| (item, false)).collect::<Vec<_>>())
+    };
+
+    let options: Vec<SOption> = tags.iter().map(|(tag, selected)| {
+        SOption {
+            selected: *selected,
+            ..SOption::new(tag.clone(), tag.clone())
+        }
+    }).collect();
+
+    // Called when a tag is selected from the list or removed from the chips
+    let onselectchange = {
+        let tags = tags.clone();
+        Callback::from(move |(value, to_selected): (AttrValue, bool)| {
+            tags.set(tags.iter().map(|(tag, selected)| {
+                (tag.clone(), if *tag == value { to_selected } else { *selected })
+            }).collect())
+        })
+    };
+
+    // Called when a new tag is created: add it, selected
+    let on_create = {
+        let tags = tags.clone();
+        Callback::from(move |tag: AttrValue| {
+            let mut new_tags = (*tags).clone();
+            new_tags.push((tag, true));
+            tags.set(new_tags)
+        })
+    };
+
+    html! {
+        <Container class="mt-5" size={ ContainerSize::Small } >
+            <h2>{ "Searchable Select Demo - Tags" }</h2>
+            <p>
+                { "With " }<code>{ "on_create" }</code>{ ", typing a text matching no option offers
+                   to create it. With " }<code>{ "chips" }</code>{ ", the selected options are
+                   displayed as removable badges instead of the title." }
+            </p>
+
+            <SearchableSelect
+                {options}
+                placeholder="Add tags..."
+                {onselectchange}
+                {on_create}
+                chips={true}
+                keep_open={true}
+            />
+        </Container>
+    }
+}
@@ -0,0 +1,260 @@
+//! Derive macros for `yew-bootstrap`, re-exported by its `derive` feature.
+//!
+//! See `yew_bootstrap::component::form::BootstrapForm` for the documentation.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Expr, Fields, LitInt, LitStr};
+
+/// Derive `BootstrapForm` for a struct with named fields. See
+/// `yew_bootstrap::component::form::BootstrapForm`.
+#[proc_macro_derive(BootstrapForm, attributes(form))]
+pub fn derive_bootstrap_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let result = match &input.data {
+        Data::Struct(data) => derive_struct(&input, &data.fields),
+        Data::Enum(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "BootstrapForm cannot be derived for enums, derive FormValue to edit an enum with a select",
+        )),
+        Data::Union(_) => Err(syn::Error::new_spanned(&input.ident, "BootstrapForm cannot be derived for unions")),
+    };
+    result.unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derive `FormValue` for an enum with unit variants, edited with a select. See
+/// `yew_bootstrap::component::form::FormValue`.
+#[proc_macro_derive(FormValue, attributes(form))]
+pub fn derive_form_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let result = match &input.data {
+        Data::Enum(data) => derive_enum(&input, data.variants.iter().collect()),
+        _ => Err(syn::Error::new_spanned(&input.ident, "FormValue can only be derived for enums with unit variants")),
+    };
+    result.unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Attributes of a field, in `#[form(...)]`
+#[derive(Default)]
+struct FormAttrs {
+    label: Option<LitStr>,
+    help: Option<LitStr>,
+    placeholder: Option<LitStr>,
+    autocomplete: Option<Expr>,
+    ctype: Option<TokenStream2>,
+    validators: Vec<TokenStream2>,
+}
+
+impl FormAttrs {
+    /// Parse the `#[form(...)]` attributes
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut result = FormAttrs::default();
+        let form = quote!(::yew_bootstrap::component::form);
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("form")) {
+            attr.parse_nested_meta(|meta| {
+                let key = meta.path.get_ident().map(|ident| ident.to_string()).unwrap_or_default();
+                match key.as_str() {
+                    "label" => result.label = Some(meta.value()?.parse()?),
+                    "help" => result.help = Some(meta.value()?.parse()?),
+                    "placeholder" => result.placeholder = Some(meta.value()?.parse()?),
+                    "autocomplete" => result.autocomplete = Some(meta.value()?.parse()?),
+                    "ctype" => {
+                        let ctype: Expr = meta.value()?.parse()?;
+                        result.ctype = Some(quote!(#ctype));
+                    },
+                    "date" => result.ctype = Some(quote!(#form::FormControlType::Date)),
+                    "password" => result.ctype = Some(quote!(#form::FormControlType::Password)),
+                    "textarea" => result.ctype = Some(quote!(#form::FormControlType::TextArea { cols: None, rows: None })),
+                    "required" => {
+                        let message = match meta.input.peek(syn::Token![=]) {
+                            true => meta.value()?.parse()?,
+                            false => LitStr::new("This field is required", proc_macro2::Span::call_site()),
+                        };
+                        result.validators.push(quote!(#form::Validator::Required(#message.into())));
+                    },
+                    "min_length" | "max_length" => {
+                        let length: LitInt = meta.value()?.parse()?;
+                        let (variant, text) = match key.as_str() {
+                            "min_length" => (format_ident!("MinLength"), "At least {} characters"),
+                            _ => (format_ident!("MaxLength"), "At most {} characters"),
+                        };
+                        let message = text.replace("{}", length.base10_digits());
+                        result.validators.push(quote!(#form::Validator::#variant(#length, #message.into())));
+                    },
+                    "pattern" => {
+                        let pattern: LitStr = meta.value()?.parse()?;
+                        result.validators.push(quote!(#form::Validator::Pattern(#pattern.into(), "Invalid format".into())));
+                    },
+                    "validator" => {
+                        let validator: Expr = meta.value()?.parse()?;
+                        result.validators.push(quote!(#validator));
+                    },
+                    _ => return Err(meta.error("unknown form attribute")),
+                }
+                Ok(())
+            })?;
+        }
+        Ok(result)
+    }
+}
+
+/// Label of a variant, in `#[form(label = "...")]`, the only attribute of variants
+fn parse_variant_label(attrs: &[syn::Attribute]) -> syn::Result<Option<LitStr>> {
+    let mut label = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("form")) {
+        attr.parse_nested_meta(|meta| match meta.path.is_ident("label") {
+            true => {
+                label = Some(meta.value()?.parse()?);
+                Ok(())
+            },
+            false => Err(meta.error("only the label form attribute is supported on enum variants")),
+        })?;
+    }
+    Ok(label)
+}
+
+/// Label from the name of a field: `first_name` becomes `First name`
+fn humanize(name: &str) -> String {
+    let name = name.trim_start_matches("r#").replace('_', " ");
+    let mut chars = name.trim().chars();
+    chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
+
+/// Implement `BootstrapForm` for a struct with named fields
+fn derive_struct(input: &DeriveInput, fields: &Fields) -> syn::Result<TokenStream2> {
+    let Fields::Named(fields) = fields else {
+        return Err(syn::Error::new_spanned(&input.ident, "BootstrapForm requires a struct with named fields"));
+    };
+
+    let form = quote!(::yew_bootstrap::component::form);
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut controls = Vec::new();
+    let mut values = Vec::new();
+    for field in &fields.named {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let attrs = FormAttrs::parse(&field.attrs)?;
+
+        let name = field_ident.to_string().trim_start_matches("r#").to_string();
+        let id = format!("{}-{}", ident, name);
+        let label = attrs.label.map(|label| label.value()).unwrap_or_else(|| humanize(&name));
+        let help = option_tokens(attrs.help.map(|help| quote!(#help.into())));
+        let placeholder = option_tokens(attrs.placeholder.map(|placeholder| quote!(#placeholder.into())));
+        let autocomplete = attrs.autocomplete.map(|autocomplete| quote!(#autocomplete))
+            .unwrap_or_else(|| quote!(#form::FormAutocompleteType::Off));
+        let ctype = option_tokens(attrs.ctype);
+        let validators = attrs.validators;
+
+        controls.push(quote! {
+            #form::field_control::<#ty>(
+                #form::FieldAttrs {
+                    name: #name.into(),
+                    id: #id.into(),
+                    label: #label.into(),
+                    help: #help,
+                    placeholder: #placeholder,
+                    autocomplete: #autocomplete,
+                    ctype: #ctype,
+                    validators: ::std::vec![#(#validators),*],
+                },
+                value.map(|value| &value.#field_ident),
+            )
+        });
+        values.push(quote!(#field_ident: #form::field_value::<#ty>(values, #name)?));
+    }
+
+    Ok(quote! {
+        impl #impl_generics #form::BootstrapForm for #ident #type_generics #where_clause {
+            fn form_controls(value: ::std::option::Option<&Self>) -> ::yew::Html {
+                [#(#controls),*].into_iter().collect()
+            }
+
+            fn from_form_values(values: &#form::FormValues) -> ::std::result::Result<Self, ::yew::AttrValue> {
+                ::std::result::Result::Ok(Self {
+                    #(#values),*
+                })
+            }
+        }
+    })
+}
+
+/// Implement `FormValue` for an enum with unit variants, displayed as a select
+fn derive_enum(input: &DeriveInput, variants: Vec<&syn::Variant>) -> syn::Result<TokenStream2> {
+    let form = quote!(::yew_bootstrap::component::form);
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut to_values = Vec::new();
+    let mut from_values = Vec::new();
+    let mut options = Vec::new();
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(variant, "FormValue requires an enum with unit variants"));
+        }
+        let variant_ident = &variant.ident;
+        let value = variant_ident.to_string();
+        let label = parse_variant_label(&variant.attrs)?.map(|label| label.value()).unwrap_or_else(|| value.clone());
+
+        to_values.push(quote!(Self::#variant_ident => #value));
+        from_values.push(quote!(#value => ::std::result::Result::Ok(Self::#variant_ident)));
+        options.push(quote! {
+            ::yew::html! {
+                <#form::SelectOption
+                    key={#value}
+                    label={#label}
+                    value={#value}
+                    selected={::std::matches!(value, ::std::option::Option::Some(Self::#variant_ident))}
+                />
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics #form::FormValue for #ident #type_generics #where_clause {
+            fn control_type() -> #form::FormControlType {
+                #form::FormControlType::Select
+            }
+
+            fn to_form_value(&self) -> ::yew::AttrValue {
+                ::yew::AttrValue::from(match self {
+                    #(#to_values),*
+                })
+            }
+
+            fn from_form_value(value: &str) -> ::std::result::Result<Self, ::yew::AttrValue> {
+                match value {
+                    #(#from_values,)*
+                    _ => ::std::result::Result::Err("Select an option".into()),
+                }
+            }
+
+            fn select_options(value: ::std::option::Option<&Self>) -> ::yew::Html {
+                [#(#options),*].into_iter().collect()
+            }
+        }
+    })
+}
+
+/// `Some(tokens)` or `None`
+fn option_tokens(tokens: Option<TokenStream2>) -> TokenStream2 {
+    match tokens {
+        Some(tokens) => quote!(::std::option::Option::Some(#tokens)),
+        None => quote!(::std::option::Option::None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn humanize_field_names() {
+        assert_eq!(humanize("name"), "Name");
+        assert_eq!(humanize("first_name"), "First name");
+        assert_eq!(humanize("r#type"), "Type");
+        assert_eq!(humanize("_"), "");
+    }
+}
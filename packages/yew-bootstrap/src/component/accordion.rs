@@ -2,6 +2,8 @@ use std::rc::Rc;
 
 use yew::prelude::*;
 
+use super::Collapse;
+
 /// # Properties of [AccordionHeader]
 #[derive(Properties, Clone, PartialEq)]
 struct AccordionHeaderProps {
@@ -11,7 +13,7 @@ struct AccordionHeaderProps {
 
     /// The title displayed in the header
     #[prop_or_default]
-    title: AttrValue,
+    title: Html,
 
     /// Classes attached to the button holding the title
     #[prop_or_default]
@@ -23,7 +25,11 @@ struct AccordionHeaderProps {
 
     /// If the associated accordion collapse is open
     #[prop_or_default]
-    expanded: bool
+    expanded: bool,
+
+    /// Called when the button is clicked
+    #[prop_or_default]
+    onclick: Callback<MouseEvent>,
 }
 
 /// # Accordion Header
@@ -40,10 +46,9 @@ fn AccordionHeader(props: &AccordionHeaderProps) -> Html {
             <button
                 class={props.button_classes.clone()} 
                 type="button" 
-                data-bs-toggle="collapse" 
-                data-bs-target={format!("#{}", props.collapse_id)} 
                 aria-expanded={props.expanded.to_string()} 
                 aria-controls={props.collapse_id.clone()}
+                onclick={props.onclick.clone()}
             >
                 { props.title.clone() }
             </button>
@@ -51,57 +56,6 @@ fn AccordionHeader(props: &AccordionHeaderProps) -> Html {
     }
 }
 
-/// # Properties of [AccordionCollapse]
-#[derive(Properties, Clone, PartialEq)]
-struct AccordionCollapseProps {
-    /// Parent [Accordion] html id attribute
-    #[prop_or(AttrValue::from("main-accordion"))]
-    parent_id: AttrValue,
-
-    /// Html id of this component
-    #[prop_or_default]
-    collapse_id: AttrValue,
-
-    /// Html id of associated header for this [AccordionItem]
-    #[prop_or_default]
-    heading_id: AttrValue,
-
-    /// Opening this item will close other items in the [Accordion]
-    #[prop_or_default]
-    stay_open: bool,
-
-    /// Classes attached to the div
-    #[prop_or_default]
-    class: Classes,
-
-    /// Inner components
-    #[prop_or_default]
-    children: Children,
-}
-
-/// # Accordion Collapse
-/// Used with [crate::component::AccordionItem] to create accordion drop downs
-/// This represents the body of the accordion item that can be opened/closed
-/// 
-/// See [AccordionCollapseProps] for a listing of properties
-///
-/// This component is not meant to be used stand-alone as it's only rendered inside of Accordions
-#[function_component]
-fn AccordionCollapse(props: &AccordionCollapseProps) -> Html {
-    if props.stay_open {
-        return html! {
-            <div id={props.collapse_id.clone()} class={props.class.clone()} aria-labelledby={props.heading_id.clone()}>
-                { for props.children.iter() }
-            </div>
-        }
-    }
-    html! {
-        <div id={props.collapse_id.clone()} class={props.class.clone()} aria-labelledby={props.heading_id.clone()} data-bs-parent={format!("#{}", props.parent_id)}>
-            { for props.children.iter() }
-        </div>
-    }
-}
-
 /// # Properties of [AccordionItem]
 #[derive(Properties, Clone, PartialEq)]
 pub struct AccordionItemProps {
@@ -109,17 +63,27 @@ pub struct AccordionItemProps {
     #[prop_or_default]
     pub title: AttrValue,
 
-    /// Item is currently open
+    /// Html displayed in this items heading instead of `title`, for example
+    /// with icons or badges
+    #[prop_or_default]
+    pub header: Option<Html>,
+
+    /// Item is initially open, when the parent [Accordion] isn't controlled by
+    /// its `open_items` property
     #[prop_or_default]
     pub expanded: bool,
 
-    /// Inner components (displayed in the [AccordionCollapse])
+    /// Inner components (displayed in the body of the item)
     #[prop_or_default]
     pub children: Children,
 
-    /// Opening this item doesn't close other items
+    /// Item is currently open, set by the parent [Accordion]
     #[prop_or_default]
-    stay_open: bool,
+    open: bool,
+
+    /// Called when the header is clicked, set by the parent [Accordion]
+    #[prop_or_default]
+    on_header_click: Callback<MouseEvent>,
 
     /// Html id attribute of parent [Accordion]
     #[prop_or(AttrValue::from("main-accordion"))]
@@ -140,47 +104,30 @@ pub fn AccordionItem(props: &AccordionItemProps) -> Html {
     let collapse_id = format!("{}-collapse-{}", props.parent_id, props.item_id);
 
     let mut button_classes = classes!("accordion-button");
-    let mut collapse_classes = classes!("accordion-collapse",  "collapse");
-
-    // TODO: Maybe hook up the `expanded` property to some state depending on `stay_open`
-    //
-    // I think in the bootstrap docs this is really only meant to show one item as expanded after loading the page
-    // However as it currently is, users may be able to set this on multiple items at once
-    // This is probably fine during initial page load since they can be closed individually
-    // But it acts weird if an end-user were to open another item as it would close all of them unless `stay_open` is true
-    // 
-    // Additionally if some other part of the page is setup to use state to open an item
-    // This will cause 2 items to be open at once even if the `stay_open` flag is false
-    // There's no real harm putting the closing of accordion items on the user, but it would be nice if there were
-    // some sort of built in way to handle this
-    //
-    // I use ssr in my project so ideally this would also not interfere with rendering server side
-    if !props.expanded {
+    if !props.open {
         button_classes.push("collapsed");
-    } else {
-        collapse_classes.push("show");
     }
 
     html! {
         <div class="accordion-item">
             <AccordionHeader 
-                title={props.title.clone()}
+                title={props.header.clone().unwrap_or_else(|| html! { props.title.clone() })}
                 heading_id={heading_id.clone()}
                 button_classes={button_classes}
                 collapse_id={collapse_id.clone()}
-                expanded={props.expanded}
+                expanded={props.open}
+                onclick={props.on_header_click.clone()}
             />
-            <AccordionCollapse
-                class={collapse_classes}
-                stay_open={props.stay_open}
-                heading_id={heading_id}
-                collapse_id={collapse_id.clone()}
-                parent_id={props.parent_id.clone()}
+            <Collapse
+                class="accordion-collapse"
+                id={AttrValue::from(collapse_id)}
+                aria_labelledby={AttrValue::from(heading_id)}
+                open={props.open}
             >
                 <div class="accordion-body">
                     { for props.children.iter() }
                 </div>
-            </AccordionCollapse>
+            </Collapse>
         </div>
     }
 }
@@ -200,6 +147,16 @@ pub struct AccordionProps {
     #[prop_or_default]
     pub stay_open: bool,
 
+    /// Indexes of the open items. When set, the accordion is controlled: the
+    /// application must update it from `on_toggle`. When unset, the accordion
+    /// manages its state itself, starting with the items which are `expanded`.
+    #[prop_or_default]
+    pub open_items: Option<Vec<usize>>,
+
+    /// Called with the indexes of the open items, when the user toggles an item
+    #[prop_or_default]
+    pub on_toggle: Callback<Vec<usize>>,
+
     // The [AccordionItem] instances controlled by this accordion
     #[prop_or_default]
     pub children: ChildrenWithProps<AccordionItem>,
@@ -255,8 +212,42 @@ pub struct AccordionProps {
 ///     }
 /// }
 /// ```
+///
+/// Example of an Accordion controlled by the application, with Html in a heading
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Accordion, AccordionItem, Badge};
+/// use yew_bootstrap::util::Color;
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let open_items = use_state(|| vec![0]);
+///     let on_toggle = { let open_items = open_items.clone(); Callback::from(move |items| open_items.set(items)) };
+///
+///     html! {
+///         <Accordion open_items={(*open_items).clone()} {on_toggle} stay_open={true}>
+///             <AccordionItem header={html! { <>{"Inbox "}<Badge style={Color::Primary}>{"4"}</Badge></> }}>
+///                 {"Messages"}
+///             </AccordionItem>
+///             <AccordionItem title="Archive">{"Old messages"}</AccordionItem>
+///         </Accordion>
+///     }
+/// }
+/// ```
 #[function_component]
 pub fn Accordion(props: &AccordionProps) -> Html {
+    let uncontrolled_items = use_state_eq(|| {
+        props
+            .children
+            .iter()
+            .enumerate()
+            .filter(|(_, child)| child.props.expanded)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>()
+    });
+    let open_items = props.open_items.clone().unwrap_or_else(|| (*uncontrolled_items).clone());
+
     let mut classes = classes!("accordion");
     if props.flush {
         classes.push("accordion-flush");
@@ -266,13 +257,59 @@ pub fn Accordion(props: &AccordionProps) -> Html {
         <div class={classes} id={props.id.clone()}>
             {
                 for props.children.iter().enumerate().map(|(index, mut child)| {
+                    let on_header_click = {
+                        let next = toggle_items(&open_items, index, props.stay_open);
+                        let uncontrolled_items = uncontrolled_items.clone();
+                        let on_toggle = props.on_toggle.clone();
+                        let controlled = props.open_items.is_some();
+                        Callback::from(move |_: MouseEvent| {
+                            if !controlled {
+                                uncontrolled_items.set(next.clone());
+                            }
+                            on_toggle.emit(next.clone());
+                        })
+                    };
                     let child_props = Rc::make_mut(&mut child.props);
                     child_props.item_id = index;
                     child_props.parent_id = props.id.clone();
-                    child_props.stay_open = props.stay_open;
+                    child_props.open = open_items.contains(&index);
+                    child_props.on_header_click = on_header_click;
                     child
                 })
             }
         </div>
     }
-}
\ No newline at end of file
+}
+
+/// Open items after toggling the item at `index`. Unless `stay_open` is set,
+/// opening an item closes the others.
+fn toggle_items(open_items: &[usize], index: usize, stay_open: bool) -> Vec<usize> {
+    if open_items.contains(&index) {
+        open_items.iter().copied().filter(|item| *item != index).collect()
+    } else if stay_open {
+        let mut items = open_items.to_vec();
+        items.push(index);
+        items.sort_unstable();
+        items
+    } else {
+        vec![index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_single_open() {
+        assert_eq!(toggle_items(&[], 1, false), vec![1]);
+        assert_eq!(toggle_items(&[0], 1, false), vec![1]);
+        assert_eq!(toggle_items(&[1], 1, false), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_toggle_stay_open() {
+        assert_eq!(toggle_items(&[2], 0, true), vec![0, 2]);
+        assert_eq!(toggle_items(&[0, 2], 2, true), vec![0]);
+    }
+}
@@ -0,0 +1,191 @@
+use yew::prelude::*;
+
+use crate::icons::BI;
+
+/// # Divider between the items of a [Breadcrumb]
+///
+/// Sets the `--bs-breadcrumb-divider` CSS variable of the breadcrumb.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum BreadcrumbDivider {
+    /// Bootstrap's default divider, `/`
+    #[default]
+    Default,
+    /// No divider
+    None,
+    /// Custom text, eg. `">"`
+    Text(AttrValue),
+    /// Custom image, from the markup of an SVG element. `<`, `>`, `#`, `%` and `"`
+    /// are escaped so it can be embedded in a data URL.
+    Svg(AttrValue),
+}
+
+impl BreadcrumbDivider {
+    /// Value of the `--bs-breadcrumb-divider` CSS variable, if not the default
+    fn css_value(&self) -> Option<String> {
+        match self {
+            Self::Default => None,
+            Self::None => Some(String::from("''")),
+            Self::Text(text) => Some(format!("'{}'", text.replace('\\', "\\\\").replace('\'', "\\'"))),
+            Self::Svg(svg) => {
+                let mut escaped = String::with_capacity(svg.len());
+                for c in svg.chars() {
+                    match c {
+                        '%' => escaped.push_str("%25"),
+                        '#' => escaped.push_str("%23"),
+                        '<' => escaped.push_str("%3C"),
+                        '>' => escaped.push_str("%3E"),
+                        '"' => escaped.push('\''),
+                        '\n' | '\r' => escaped.push(' '),
+                        c => escaped.push(c),
+                    }
+                }
+                Some(format!("url(\"data:image/svg+xml,{escaped}\")"))
+            }
+        }
+    }
+}
+
+/// # Properties of [Breadcrumb]
+#[derive(Properties, Clone, PartialEq)]
+pub struct BreadcrumbProps {
+    /// Items of the breadcrumb, typically [BreadcrumbItem]
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Divider between items
+    #[prop_or_default]
+    pub divider: BreadcrumbDivider,
+
+    /// Accessible label of the navigation
+    #[prop_or(AttrValue::from("breadcrumb"))]
+    pub label: AttrValue,
+}
+
+/// # Breadcrumb component
+/// Location of the current page in the navigational hierarchy, parent of
+/// [BreadcrumbItem] components.
+///
+/// See [BreadcrumbProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Breadcrumb, BreadcrumbDivider, BreadcrumbItem};
+/// use yew_bootstrap::icons::BI;
+///
+/// fn test() -> Html {
+///     html!{
+///         <Breadcrumb divider={BreadcrumbDivider::Text(">".into())}>
+///             <BreadcrumbItem text="Home" url="/" icon={&BI::HOUSE} />
+///             <BreadcrumbItem text="Library" url="/library" />
+///             <BreadcrumbItem text="Data" active={true} />
+///         </Breadcrumb>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Breadcrumb(props: &BreadcrumbProps) -> Html {
+    let style = props
+        .divider
+        .css_value()
+        .map(|value| format!("--bs-breadcrumb-divider: {value};"));
+
+    html! {
+        <nav aria-label={props.label.clone()} {style}>
+            <ol class={classes!("breadcrumb", props.class.clone())}>
+                { for props.children.iter() }
+            </ol>
+        </nav>
+    }
+}
+
+/// # Properties of [BreadcrumbItem]
+#[derive(Properties, Clone, PartialEq)]
+pub struct BreadcrumbItemProps {
+    /// Text of the item
+    #[prop_or_default]
+    pub text: AttrValue,
+
+    /// Extra components, displayed after the text
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Item is the current page, it is not rendered as a link
+    #[prop_or_default]
+    pub active: bool,
+
+    /// If provided, text is inside a link
+    #[prop_or_default]
+    pub url: Option<AttrValue>,
+
+    /// Callback when clicked.
+    ///
+    /// **Tip:** To make browsers show a "link" mouse cursor for the [BreadcrumbItem],
+    /// set `url="#"` and call [`Event::prevent_default()`] from your callback.
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
+
+    /// Optional icon
+    #[prop_or_default]
+    pub icon: Option<&'static BI>,
+}
+
+/// # Item of a [Breadcrumb]
+///
+/// See [BreadcrumbItemProps] for a listing of properties.
+#[function_component]
+pub fn BreadcrumbItem(props: &BreadcrumbItemProps) -> Html {
+    let content = html! {
+        <>
+            if let Some(icon) = props.icon {
+                {icon}{" "}
+            }
+            { props.text.clone() }
+            { for props.children.iter() }
+        </>
+    };
+
+    if props.active {
+        html! {
+            <li class={classes!("breadcrumb-item", "active", props.class.clone())} aria-current="page">
+                { content }
+            </li>
+        }
+    } else {
+        html! {
+            <li class={classes!("breadcrumb-item", props.class.clone())}>
+                <a href={props.url.clone()} onclick={props.onclick.clone()}>{ content }</a>
+            </li>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_divider_text() {
+        assert_eq!(BreadcrumbDivider::Default.css_value(), None);
+        assert_eq!(BreadcrumbDivider::None.css_value().as_deref(), Some("''"));
+        assert_eq!(BreadcrumbDivider::Text(">".into()).css_value().as_deref(), Some("'>'"));
+        assert_eq!(BreadcrumbDivider::Text("it's".into()).css_value().as_deref(), Some("'it\\'s'"));
+    }
+
+    #[test]
+    fn test_divider_svg() {
+        let svg = r##"<svg xmlns="http://www.w3.org/2000/svg" width="8" height="8"><path d="M2.5 0L1 1.5 3.5 4 1 6.5 2.5 8l4-4-4-4z" fill="#6c757d"/></svg>"##;
+        assert_eq!(
+            BreadcrumbDivider::Svg(svg.into()).css_value().as_deref(),
+            Some("url(\"data:image/svg+xml,%3Csvg xmlns='http://www.w3.org/2000/svg' width='8' height='8'%3E%3Cpath d='M2.5 0L1 1.5 3.5 4 1 6.5 2.5 8l4-4-4-4z' fill='%236c757d'/%3E%3C/svg%3E\")")
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::component::ModalContext;
 use crate::util::Color;
 use yew::prelude::*;
 
@@ -70,6 +71,9 @@ impl Default for ButtonSize {
 ///     }
 /// }
 /// ```
+///
+/// Inside a [crate::component::Modal] controlled by its `show` property, `modal_dismiss`
+/// calls the `on_close` callback of the modal instead of relying on Bootstrap's JavaScript.
 pub struct Button {}
 
 /// # Properties for [Button]
@@ -183,9 +187,18 @@ impl Component for Button {
         }
         classes.push(props.class.clone());
 
-        let modal_dismiss = match props.modal_dismiss {
-            true => "modal",
-            false => "",
+        // A modal controlled by Yew is closed through its context, otherwise by Bootstrap
+        let modal = ctx.link().context::<ModalContext>(Callback::noop()).map(|(modal, _)| modal);
+        let (modal_dismiss, onclick) = match (props.modal_dismiss, modal) {
+            (true, Some(modal)) => {
+                let onclick = props.onclick.clone();
+                ("", Callback::from(move |event| {
+                    onclick.emit(event);
+                    modal.close.emit(());
+                }))
+            }
+            (true, None) => ("modal", props.onclick.clone()),
+            (false, _) => ("", props.onclick.clone()),
         };
 
         if let Some(target) = &props.modal_target {
@@ -194,7 +207,7 @@ impl Component for Button {
                     class={classes}
                     disabled={props.disabled}
                     name={props.name.clone()}
-                    onclick={props.onclick.clone()}
+                    onclick={onclick.clone()}
                     data-bs-toggle="modal"
                     data-bs-target={format!("#{}",target.clone())}
                     ref={props.node_ref.clone()}
@@ -210,7 +223,7 @@ impl Component for Button {
                     class={classes}
                     disabled={props.disabled}
                     name={props.name.clone()}
-                    onclick={props.onclick.clone()}
+                    onclick={onclick.clone()}
                     data-bs-dismiss={modal_dismiss}
                     href={url.clone()}
                     target={props.target.clone()}
@@ -227,7 +240,7 @@ impl Component for Button {
                     class={classes}
                     disabled={props.disabled}
                     name={props.name.clone()}
-                    onclick={props.onclick.clone()}
+                    onclick={onclick.clone()}
                     data-bs-dismiss={modal_dismiss}
                     ref={props.node_ref.clone()}
                     id={props.id.clone()}
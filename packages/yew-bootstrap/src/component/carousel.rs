@@ -0,0 +1,521 @@
+use std::rc::Rc;
+
+use gloo_timers::callback::Timeout;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use crate::icons::BI;
+
+/// Duration of the `transform` and `opacity` transitions of a [Carousel], in milliseconds
+const SLIDE_DURATION: u32 = 600;
+
+/// Minimal horizontal distance of a touch gesture to change slide, in pixels
+const SWIPE_THRESHOLD: i32 = 40;
+
+/// Direction of the transition between two slides
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SlideDirection {
+    Next,
+    Prev,
+}
+
+/// Transition in progress between two slides
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Slide {
+    from: usize,
+    to: usize,
+    direction: SlideDirection,
+    /// False while the next slide is laid out at its initial position, true once
+    /// both slides are moving
+    moving: bool,
+}
+
+/// # Properties of [Carousel]
+#[derive(Properties, Clone, PartialEq)]
+pub struct CarouselProps {
+    /// Slides of the carousel
+    #[prop_or_default]
+    pub children: ChildrenWithProps<CarouselItem>,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Optional HTML id of the carousel
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+
+    /// Index of the active slide. When set, the carousel is controlled: the
+    /// application must update it from `on_slide`. When unset, the carousel
+    /// manages the active slide itself, starting with the first one.
+    #[prop_or_default]
+    pub active_index: Option<usize>,
+
+    /// Called with the index of the requested slide, when the user or the
+    /// autoplay changes the slide
+    #[prop_or_default]
+    pub on_slide: Callback<usize>,
+
+    /// Called with the index of the active slide once the transition ended
+    #[prop_or_default]
+    pub on_slid: Callback<usize>,
+
+    /// Crossfade slides instead of sliding them
+    #[prop_or_default]
+    pub fade: bool,
+
+    /// Show the indicators, one button per slide
+    #[prop_or_default]
+    pub indicators: bool,
+
+    /// Show the previous and next controls, default true
+    #[prop_or(true)]
+    pub controls: bool,
+
+    /// Icon of the previous control, Bootstrap's default icon if unset
+    #[prop_or_default]
+    pub prev_icon: Option<&'static BI>,
+
+    /// Icon of the next control, Bootstrap's default icon if unset
+    #[prop_or_default]
+    pub next_icon: Option<&'static BI>,
+
+    /// Automatically cycle through slides
+    #[prop_or_default]
+    pub autoplay: bool,
+
+    /// Delay between two slides when `autoplay` is set, in milliseconds. Can be
+    /// overridden by each [CarouselItem]. Default 5000.
+    #[prop_or(5000)]
+    pub interval: u32,
+
+    /// Pause the autoplay while the pointer is over the carousel, default true
+    #[prop_or(true)]
+    pub pause_on_hover: bool,
+
+    /// Cycle from the last slide to the first one and conversely, default true
+    #[prop_or(true)]
+    pub wrap: bool,
+
+    /// Change slides with <kbd>←</kbd> and <kbd>→</kbd> keys, default true
+    #[prop_or(true)]
+    pub keyboard: bool,
+
+    /// Change slides with swipe gestures on touchscreens, default true
+    #[prop_or(true)]
+    pub touch: bool,
+
+    /// Dark controls, indicators and captions, for light slides
+    #[prop_or_default]
+    pub dark: bool,
+}
+
+/// # Carousel component
+/// Slideshow cycling through [CarouselItem] children.
+///
+/// This component is implemented with Yew instead of Bootstrap's JavaScript, so
+/// slides can be modified at any time. The active slide can either be managed by
+/// the carousel, or controlled with `active_index` and `on_slide`.
+///
+/// See [CarouselProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Carousel, CarouselCaption, CarouselItem};
+/// use yew_bootstrap::icons::BI;
+///
+/// fn test() -> Html {
+///     html!{
+///         <Carousel indicators={true} autoplay={true} prev_icon={&BI::CHEVRON_LEFT} next_icon={&BI::CHEVRON_RIGHT}>
+///             <CarouselItem>
+///                 <img src="first.png" class="d-block w-100" alt="First slide" />
+///                 <CarouselCaption><h5>{"First slide"}</h5></CarouselCaption>
+///             </CarouselItem>
+///             <CarouselItem interval={10000}>
+///                 <img src="second.png" class="d-block w-100" alt="Second slide" />
+///             </CarouselItem>
+///         </Carousel>
+///     }
+/// }
+/// ```
+///
+/// ## Controlled example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Carousel, CarouselItem};
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let index = use_state(|| 0);
+///     let on_slide = { let index = index.clone(); Callback::from(move |i| index.set(i)) };
+///
+///     html!{
+///         <>
+///             <p>{format!("Slide {}", *index + 1)}</p>
+///             <Carousel active_index={*index} {on_slide} fade={true}>
+///                 <CarouselItem>{"First"}</CarouselItem>
+///                 <CarouselItem>{"Second"}</CarouselItem>
+///             </Carousel>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Carousel(props: &CarouselProps) -> Html {
+    let count = props.children.len();
+    let inner_ref = use_node_ref();
+
+    let uncontrolled_index = use_state_eq(|| 0);
+    let requested = props.active_index.unwrap_or(*uncontrolled_index).min(count.saturating_sub(1));
+    let displayed = use_state_eq(|| requested);
+    let slide = use_state_eq(|| None::<Slide>);
+    // Direction of the slide requested by the controls, otherwise deduced from indexes
+    let direction_hint = use_mut_ref(|| None::<SlideDirection>);
+    let hovered = use_state_eq(|| false);
+    let touch_start = use_mut_ref(|| None::<i32>);
+
+    // Start a transition when another slide is requested
+    {
+        let displayed = displayed.clone();
+        let slide = slide.clone();
+        let direction_hint = direction_hint.clone();
+        use_effect_with((requested, *displayed, slide.is_none()), move |(requested, from, idle)| {
+            if *idle && requested != from {
+                let direction = direction_hint.borrow_mut().take().unwrap_or(if requested > from {
+                    SlideDirection::Next
+                } else {
+                    SlideDirection::Prev
+                });
+                slide.set(Some(Slide { from: *from, to: *requested, direction, moving: false }));
+            }
+        });
+    }
+
+    // Move the slides once the layout is computed, then end the transition
+    {
+        let displayed = displayed.clone();
+        let slide = slide.clone();
+        let on_slid = props.on_slid.clone();
+        let inner_ref = inner_ref.clone();
+        use_effect_with(*slide, move |current| {
+            let timeout = match *current {
+                Some(current) if !current.moving => {
+                    if let Some(element) = inner_ref.cast::<HtmlElement>() {
+                        // Reading the size forces a reflow
+                        let _ = element.offset_height();
+                    }
+                    slide.set(Some(Slide { moving: true, ..current }));
+                    None
+                }
+                Some(current) => Some(Timeout::new(SLIDE_DURATION, move || {
+                    displayed.set(current.to);
+                    slide.set(None);
+                    on_slid.emit(current.to);
+                })),
+                None => None,
+            };
+            move || drop(timeout)
+        });
+    }
+
+    let target = slide.map(|slide| slide.to).unwrap_or(*displayed).min(count.saturating_sub(1));
+
+    let go_to = {
+        let uncontrolled_index = uncontrolled_index.clone();
+        let direction_hint = direction_hint.clone();
+        let on_slide = props.on_slide.clone();
+        let controlled = props.active_index.is_some();
+        let busy = slide.is_some();
+        Rc::new(move |index: usize, direction: Option<SlideDirection>| {
+            if busy || index == target || index >= count {
+                return;
+            }
+            *direction_hint.borrow_mut() = direction;
+            if !controlled {
+                uncontrolled_index.set(index);
+            }
+            on_slide.emit(index);
+        })
+    };
+
+    let next = {
+        let go_to = go_to.clone();
+        let wrap = props.wrap;
+        Rc::new(move || {
+            if target + 1 < count {
+                go_to(target + 1, Some(SlideDirection::Next));
+            } else if wrap {
+                go_to(0, Some(SlideDirection::Next));
+            }
+        })
+    };
+
+    let prev = {
+        let go_to = go_to.clone();
+        let wrap = props.wrap;
+        Rc::new(move || {
+            if target > 0 {
+                go_to(target - 1, Some(SlideDirection::Prev));
+            } else if wrap && count > 0 {
+                go_to(count - 1, Some(SlideDirection::Prev));
+            }
+        })
+    };
+
+    // Autoplay, restarted after each slide
+    let interval = props
+        .children
+        .iter()
+        .nth(target)
+        .and_then(|item| item.props.interval)
+        .unwrap_or(props.interval);
+    let playing = props.autoplay && count > 1 && !(props.pause_on_hover && *hovered);
+    {
+        let next = next.clone();
+        use_effect_with((playing, target, slide.is_none(), interval), move |(playing, _, idle, interval)| {
+            let timeout = (*playing && *idle).then(|| Timeout::new(*interval, move || next()));
+            move || drop(timeout)
+        });
+    }
+
+    let onkeydown = {
+        let next = next.clone();
+        let prev = prev.clone();
+        let keyboard = props.keyboard;
+        Callback::from(move |event: KeyboardEvent| {
+            if !keyboard {
+                return;
+            }
+            match event.key().as_str() {
+                "ArrowLeft" => {
+                    event.prevent_default();
+                    prev();
+                }
+                "ArrowRight" => {
+                    event.prevent_default();
+                    next();
+                }
+                _ => (),
+            }
+        })
+    };
+
+    let onmouseenter = {
+        let hovered = hovered.clone();
+        Callback::from(move |_: MouseEvent| hovered.set(true))
+    };
+    let onmouseleave = {
+        let hovered = hovered.clone();
+        Callback::from(move |_: MouseEvent| hovered.set(false))
+    };
+
+    let ontouchstart = {
+        let touch_start = touch_start.clone();
+        let touch = props.touch;
+        Callback::from(move |event: TouchEvent| {
+            if touch && event.touches().length() == 1 {
+                *touch_start.borrow_mut() = event.touches().get(0).map(|t| t.client_x());
+            }
+        })
+    };
+    let ontouchend = {
+        let next = next.clone();
+        let prev = prev.clone();
+        Callback::from(move |event: TouchEvent| {
+            let start = touch_start.borrow_mut().take();
+            let end = event.changed_touches().get(0).map(|t| t.client_x());
+            if let (Some(start), Some(end)) = (start, end) {
+                let delta = end - start;
+                if delta > SWIPE_THRESHOLD {
+                    prev();
+                } else if delta < -SWIPE_THRESHOLD {
+                    next();
+                }
+            }
+        })
+    };
+
+    let mut classes = classes!("carousel", "slide");
+    if props.fade {
+        classes.push("carousel-fade");
+    }
+    if props.dark {
+        classes.push("carousel-dark");
+    }
+    classes.push(props.class.clone());
+
+    let active = (*displayed).min(count.saturating_sub(1));
+
+    html! {
+        <div
+            class={classes}
+            id={props.id.clone()}
+            {onkeydown}
+            {onmouseenter}
+            {onmouseleave}
+            {ontouchstart}
+            {ontouchend}
+        >
+            if props.indicators {
+                <div class="carousel-indicators">
+                    {
+                        for (0..count).map(|index| {
+                            let go_to = go_to.clone();
+                            let onclick = Callback::from(move |_: MouseEvent| go_to(index, None));
+                            html! {
+                                <button
+                                    type="button"
+                                    class={(index == target).then_some("active")}
+                                    aria-current={(index == target).then_some("true")}
+                                    aria-label={format!("Slide {}", index + 1)}
+                                    {onclick}
+                                ></button>
+                            }
+                        })
+                    }
+                </div>
+            }
+            <div class="carousel-inner" ref={inner_ref}>
+                {
+                    for props.children.iter().enumerate().map(|(index, mut child)| {
+                        let child_props = Rc::make_mut(&mut child.props);
+                        child_props.slide_classes = slide_classes(index, active, *slide);
+                        child
+                    })
+                }
+            </div>
+            if props.controls {
+                <button class="carousel-control-prev" type="button" onclick={move |_| prev()}>
+                    {
+                        match props.prev_icon {
+                            Some(icon) => html! { <span class="fs-1" aria-hidden="true">{ icon }</span> },
+                            None => html! { <span class="carousel-control-prev-icon" aria-hidden="true"></span> },
+                        }
+                    }
+                    <span class="visually-hidden">{ "Previous" }</span>
+                </button>
+                <button class="carousel-control-next" type="button" onclick={move |_| next()}>
+                    {
+                        match props.next_icon {
+                            Some(icon) => html! { <span class="fs-1" aria-hidden="true">{ icon }</span> },
+                            None => html! { <span class="carousel-control-next-icon" aria-hidden="true"></span> },
+                        }
+                    }
+                    <span class="visually-hidden">{ "Next" }</span>
+                </button>
+            }
+        </div>
+    }
+}
+
+/// Classes of the slide at `index`, during the transition `slide`
+fn slide_classes(index: usize, active: usize, slide: Option<Slide>) -> Classes {
+    let Some(slide) = slide else {
+        return if index == active { classes!("active") } else { Classes::new() };
+    };
+
+    let (order, end) = match slide.direction {
+        SlideDirection::Next => ("carousel-item-next", "carousel-item-start"),
+        SlideDirection::Prev => ("carousel-item-prev", "carousel-item-end"),
+    };
+    let mut classes = if index == slide.from {
+        classes!("active")
+    } else if index == slide.to {
+        classes!(order)
+    } else {
+        return Classes::new();
+    };
+    if slide.moving {
+        classes.push(end);
+    }
+    classes
+}
+
+/// # Properties of [CarouselItem]
+#[derive(Properties, Clone, PartialEq)]
+pub struct CarouselItemProps {
+    /// Content of the slide, typically an image and a [CarouselCaption]
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Delay before the next slide when this one is active, overrides the
+    /// `interval` of the [Carousel]
+    #[prop_or_default]
+    pub interval: Option<u32>,
+
+    /// Classes set by the parent [Carousel] to animate the slide
+    #[prop_or_default]
+    slide_classes: Classes,
+}
+
+/// # A single slide, child of [Carousel]
+///
+/// See [CarouselItemProps] for a listing of properties.
+#[function_component]
+pub fn CarouselItem(props: &CarouselItemProps) -> Html {
+    html! {
+        <div class={classes!("carousel-item", props.slide_classes.clone(), props.class.clone())}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// # Properties of [CarouselCaption]
+#[derive(Properties, Clone, PartialEq)]
+pub struct CarouselCaptionProps {
+    /// Content of the caption
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults, for example
+    /// `d-none d-md-block` to hide the caption on small screens.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Caption of a [CarouselItem]
+///
+/// See [CarouselCaptionProps] for a listing of properties.
+#[function_component]
+pub fn CarouselCaption(props: &CarouselCaptionProps) -> Html {
+    html! {
+        <div class={classes!("carousel-caption", props.class.clone())}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slide_classes_idle() {
+        assert_eq!(slide_classes(1, 1, None), classes!("active"));
+        assert_eq!(slide_classes(0, 1, None), Classes::new());
+    }
+
+    #[test]
+    fn test_slide_classes_next() {
+        let mut slide = Slide { from: 0, to: 1, direction: SlideDirection::Next, moving: false };
+        assert_eq!(slide_classes(0, 0, Some(slide)), classes!("active"));
+        assert_eq!(slide_classes(1, 0, Some(slide)), classes!("carousel-item-next"));
+        assert_eq!(slide_classes(2, 0, Some(slide)), Classes::new());
+
+        slide.moving = true;
+        assert_eq!(slide_classes(0, 0, Some(slide)), classes!("active", "carousel-item-start"));
+        assert_eq!(slide_classes(1, 0, Some(slide)), classes!("carousel-item-next", "carousel-item-start"));
+    }
+
+    #[test]
+    fn test_slide_classes_prev() {
+        let slide = Slide { from: 0, to: 2, direction: SlideDirection::Prev, moving: true };
+        assert_eq!(slide_classes(0, 0, Some(slide)), classes!("active", "carousel-item-end"));
+        assert_eq!(slide_classes(2, 0, Some(slide)), classes!("carousel-item-prev", "carousel-item-end"));
+    }
+}
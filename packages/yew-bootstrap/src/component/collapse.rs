@@ -0,0 +1,160 @@
+use yew::prelude::*;
+
+use crate::util::{use_transition, TransitionStage, COLLAPSE_DURATION};
+
+/// # Properties of [Collapse]
+#[derive(Properties, Clone, PartialEq)]
+pub struct CollapseProps {
+    /// Collapsible content
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Optional HTML id of the collapse, referenced by the `aria-controls`
+    /// attribute of its trigger
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+
+    /// Optional HTML id of the element labelling the content (`aria-labelledby`)
+    #[prop_or_default]
+    pub aria_labelledby: Option<AttrValue>,
+
+    /// Content is shown
+    #[prop_or_default]
+    pub open: bool,
+
+    /// Animate the width instead of the height. The content should have a fixed
+    /// width.
+    #[prop_or_default]
+    pub horizontal: bool,
+}
+
+/// # Collapse component
+/// Shows and hides content, animating its height (or width).
+///
+/// This component is implemented with Yew instead of Bootstrap's JavaScript, the
+/// content is shown when `open` is true. See [use_collapse] to manage this state
+/// and the attributes of the trigger element.
+///
+/// See [CollapseProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Collapse, use_collapse};
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let collapse = use_collapse("collapse-example", false);
+///
+///     html!{
+///         <>
+///             <button
+///                 class="btn btn-primary"
+///                 type="button"
+///                 onclick={collapse.toggle.clone()}
+///                 aria-expanded={collapse.aria_expanded()}
+///                 aria-controls={collapse.id.clone()}
+///             >
+///                 {"Toggle"}
+///             </button>
+///             <Collapse id={collapse.id.clone()} open={collapse.open}>
+///                 <div class="card card-body">{"Collapsible content"}</div>
+///             </Collapse>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Collapse(props: &CollapseProps) -> Html {
+    let collapse_ref = use_node_ref();
+    let stage = use_transition(props.open, COLLAPSE_DURATION, collapse_ref.clone(), false);
+
+    // Size of the content, measured while it is laid out
+    let size = || {
+        collapse_ref.cast::<web_sys::Element>().map(|element| {
+            if props.horizontal {
+                element.scroll_width()
+            } else {
+                element.scroll_height()
+            }
+        })
+    };
+
+    // Like Bootstrap, the transition goes from 0 to the measured size when
+    // showing, and from the measured size to 0 when hiding
+    let (mut classes, size) = match stage {
+        TransitionStage::Hidden => (classes!("collapse"), None),
+        TransitionStage::Entering | TransitionStage::Hiding => (classes!("collapsing"), None),
+        TransitionStage::Showing | TransitionStage::Exiting => (classes!("collapsing"), size()),
+        TransitionStage::Shown => (classes!("collapse", "show"), None),
+    };
+    if props.horizontal {
+        classes.push("collapse-horizontal");
+    }
+    classes.push(props.class.clone());
+
+    let dimension = if props.horizontal { "width" } else { "height" };
+    let style = size.map(|size| format!("{dimension}: {size}px;"));
+
+    html! {
+        <div
+            ref={collapse_ref}
+            class={classes}
+            id={props.id.clone()}
+            aria-labelledby={props.aria_labelledby.clone()}
+            {style}
+        >
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// # State of a [Collapse], see [use_collapse]
+#[derive(Clone, PartialEq)]
+pub struct UseCollapseHandle {
+    /// HTML id of the [Collapse]
+    pub id: AttrValue,
+    /// Content is shown
+    pub open: bool,
+    /// Toggle the content, for the `onclick` property of the trigger
+    pub toggle: Callback<MouseEvent>,
+    /// Show (true) or hide (false) the content
+    pub set_open: Callback<bool>,
+}
+
+impl UseCollapseHandle {
+    /// Value of the `aria-expanded` attribute of the trigger
+    pub fn aria_expanded(&self) -> AttrValue {
+        AttrValue::from(if self.open { "true" } else { "false" })
+    }
+}
+
+/// # Collapse hook
+///
+/// Manages the state of a [Collapse] with the HTML id `id`, initially shown if
+/// `open` is true. The returned [UseCollapseHandle] provides the callbacks and
+/// attributes of the element toggling it, see [Collapse] for an example.
+#[hook]
+pub fn use_collapse(id: impl Into<AttrValue>, open: bool) -> UseCollapseHandle {
+    let state = use_state_eq(|| open);
+
+    let toggle = {
+        let state = state.clone();
+        Callback::from(move |_: MouseEvent| state.set(!*state))
+    };
+    let set_open = {
+        let state = state.clone();
+        Callback::from(move |open: bool| state.set(open))
+    };
+
+    UseCollapseHandle {
+        id: id.into(),
+        open: *state,
+        toggle,
+        set_open,
+    }
+}
@@ -0,0 +1,698 @@
+use gloo_events::EventListener;
+use popper_rs::{
+    prelude::{use_popper, Modifier, Offset, Options, Placement, Strategy},
+    state::ApplyAttributes,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::{platform::spawn_local, prelude::*};
+
+use crate::component::ButtonSize;
+use crate::component::form::InputGroupContext;
+use crate::icons::BI;
+use crate::util::Color;
+
+/// Direction in which a [DropdownMenu] opens
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DropDirection {
+    /// Below the toggle, aligned on its start
+    #[default]
+    Down,
+    /// Below the toggle, centered. Requires Bootstrap 5.3 for the styling.
+    DownCentered,
+    /// Above the toggle, aligned on its start
+    Up,
+    /// Above the toggle, centered. Requires Bootstrap 5.3 for the styling.
+    UpCentered,
+    /// On the start side of the toggle
+    Start,
+    /// On the end side of the toggle
+    End,
+}
+
+/// Clicks closing a [Dropdown]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DropdownAutoClose {
+    /// Clicks inside or outside the menu
+    #[default]
+    Always,
+    /// Clicks inside the menu only
+    Inside,
+    /// Clicks outside the menu only
+    Outside,
+    /// The menu is only closed by the toggle or the <kbd>Escape</kbd> key
+    Manual,
+}
+
+/// # Context of a [Dropdown]
+///
+/// Provided by a [Dropdown] to its children, so that [DropdownToggle],
+/// [DropdownMenu] or custom components can share its state.
+#[derive(Clone, PartialEq)]
+pub struct DropdownContext {
+    /// Menu is shown
+    pub show: bool,
+    /// Request the menu to be shown or hidden
+    pub set_show: Callback<bool>,
+    pub(crate) toggle_ref: NodeRef,
+    pub(crate) menu_ref: NodeRef,
+    pub(crate) direction: DropDirection,
+}
+
+/// # Properties of [Dropdown]
+#[derive(Properties, Clone, PartialEq)]
+pub struct DropdownProps {
+    /// Inner components, a [DropdownToggle] and a [DropdownMenu]
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Optional HTML id of the dropdown
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+
+    /// Menu is shown. When set, the dropdown is controlled: the application must
+    /// update it from `on_toggle`. When unset, the dropdown manages its state itself.
+    #[prop_or_default]
+    pub show: Option<bool>,
+
+    /// Called when the user requests to show (true) or hide (false) the menu
+    #[prop_or_default]
+    pub on_toggle: Callback<bool>,
+
+    /// Direction in which the menu opens
+    #[prop_or_default]
+    pub direction: DropDirection,
+
+    /// Clicks closing the menu
+    #[prop_or_default]
+    pub auto_close: DropdownAutoClose,
+
+    /// Render as a button group, required for split toggles and for dropdowns
+    /// inside a [crate::component::ButtonGroup]
+    #[prop_or_default]
+    pub group: bool,
+}
+
+/// # Dropdown component
+/// Toggleable menu, parent of a [DropdownToggle] and a [DropdownMenu].
+///
+/// This component is implemented with Yew instead of Bootstrap's JavaScript, and the
+/// menu is positioned with [`popper-rs`](https://github.com/ctron/popper-rs/), like
+/// [crate::component::Tooltip].
+///
+/// When the menu is shown, <kbd>↑</kbd> and <kbd>↓</kbd> move the focus between its
+/// items, and <kbd>Escape</kbd> closes it.
+///
+/// Inside an [InputGroup][crate::component::form::InputGroup], the toggle and the
+/// menu are rendered directly in the group, without a container.
+///
+/// See [DropdownProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{
+///     DropDirection, Dropdown, DropdownDivider, DropdownHeader, DropdownItem, DropdownMenu, DropdownToggle,
+/// };
+/// use yew_bootstrap::util::Color;
+///
+/// fn test() -> Html {
+///     html!{
+///         <Dropdown direction={DropDirection::Up}>
+///             <DropdownToggle style={Color::Secondary} text="Dropdown" />
+///             <DropdownMenu>
+///                 <DropdownHeader text="Header" />
+///                 <DropdownItem text="Action" onclick={Callback::from(|_| ())} />
+///                 <DropdownItem text="Link" url="#" />
+///                 <DropdownDivider />
+///                 <DropdownItem text="Disabled" disabled={true} />
+///             </DropdownMenu>
+///         </Dropdown>
+///     }
+/// }
+/// ```
+///
+/// ## Split button, controlled
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Dropdown, DropdownAutoClose, DropdownItem, DropdownMenu, DropdownToggle};
+/// use yew_bootstrap::util::Color;
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let show = use_state(|| false);
+///     let on_toggle = { let show = show.clone(); Callback::from(move |s| show.set(s)) };
+///
+///     html!{
+///         <Dropdown group={true} show={*show} {on_toggle} auto_close={DropdownAutoClose::Outside}>
+///             <DropdownToggle style={Color::Danger} text="Action" split={true} onclick={Callback::from(|_| ())} />
+///             <DropdownMenu align_end={true}>
+///                 <DropdownItem text="Another action" />
+///             </DropdownMenu>
+///         </Dropdown>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Dropdown(props: &DropdownProps) -> Html {
+    let toggle_ref = use_node_ref();
+    let menu_ref = use_node_ref();
+    let uncontrolled_show = use_state_eq(|| false);
+    let show = props.show.unwrap_or(*uncontrolled_show);
+    // Focus the first item once the menu is opened with the keyboard
+    let focus_first = use_mut_ref(|| false);
+
+    let set_show = {
+        let uncontrolled_show = uncontrolled_show.clone();
+        let on_toggle = props.on_toggle.clone();
+        let controlled = props.show.is_some();
+        Callback::from(move |value: bool| {
+            if value != show {
+                if !controlled {
+                    uncontrolled_show.set(value);
+                }
+                on_toggle.emit(value);
+            }
+        })
+    };
+
+    // Close the menu on clicks, except on the toggle which handles them itself
+    {
+        let set_show = set_show.clone();
+        let toggle_ref = toggle_ref.clone();
+        let menu_ref = menu_ref.clone();
+        use_effect_with((show, props.auto_close), move |(show, auto_close)| {
+            let auto_close = *auto_close;
+            let listener = (*show && auto_close != DropdownAutoClose::Manual).then(|| {
+                EventListener::new(&gloo_utils::document(), "click", move |event| {
+                    let Some(target) = event.target().and_then(|target| target.dyn_into::<Element>().ok()) else {
+                        return;
+                    };
+                    if toggle_ref.get().is_some_and(|toggle| toggle.contains(Some(&target))) {
+                        return;
+                    }
+                    let close = if menu_ref.get().is_some_and(|menu| menu.contains(Some(&target))) {
+                        matches!(auto_close, DropdownAutoClose::Always | DropdownAutoClose::Inside)
+                            && !is_form_element(&target)
+                    } else {
+                        matches!(auto_close, DropdownAutoClose::Always | DropdownAutoClose::Outside)
+                    };
+                    if close {
+                        set_show.emit(false);
+                    }
+                })
+            });
+            move || drop(listener)
+        });
+    }
+
+    {
+        let menu_ref = menu_ref.clone();
+        let focus_first = focus_first.clone();
+        use_effect_with(show, move |show| {
+            if *show && std::mem::take(&mut *focus_first.borrow_mut()) {
+                focus_item(&menu_ref, true);
+            }
+        });
+    }
+
+    let onkeydown = {
+        let set_show = set_show.clone();
+        let toggle_ref = toggle_ref.clone();
+        let menu_ref = menu_ref.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            let key = event.key();
+            match key.as_str() {
+                "Escape" if show => {
+                    event.prevent_default();
+                    set_show.emit(false);
+                    if let Some(toggle) = toggle_ref.cast::<HtmlElement>() {
+                        let _ = toggle.focus();
+                    }
+                }
+                "ArrowDown" | "ArrowUp" => {
+                    // Keep the arrows for text fields inside the menu
+                    let in_text_field = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<Element>().ok())
+                        .is_some_and(|target| matches!(target.tag_name().as_str(), "INPUT" | "TEXTAREA"));
+                    if in_text_field {
+                        return;
+                    }
+                    event.prevent_default();
+                    if show {
+                        focus_item(&menu_ref, key == "ArrowDown");
+                    } else {
+                        *focus_first.borrow_mut() = true;
+                        set_show.emit(true);
+                    }
+                }
+                _ => (),
+            }
+        })
+    };
+
+    // Inside an InputGroup there is no container to catch the keyboard events
+    let in_input_group = use_context::<InputGroupContext>().is_some();
+    let latest_onkeydown = use_mut_ref(|| onkeydown.clone());
+    *latest_onkeydown.borrow_mut() = onkeydown.clone();
+    {
+        let toggle_ref = toggle_ref.clone();
+        let menu_ref = menu_ref.clone();
+        use_effect_with(in_input_group, move |in_input_group| {
+            let listeners: Vec<_> = [toggle_ref, menu_ref]
+                .iter()
+                .filter(|_| *in_input_group)
+                .filter_map(|node| node.get())
+                .map(|node| {
+                    let onkeydown = latest_onkeydown.clone();
+                    EventListener::new(&node, "keydown", move |event| {
+                        // Not borrowed while handled, as handling it renders the dropdown
+                        let onkeydown = onkeydown.borrow().clone();
+                        onkeydown.emit(event.clone().unchecked_into::<KeyboardEvent>())
+                    })
+                })
+                .collect();
+            move || drop(listeners)
+        });
+    }
+
+    let mut classes = Classes::new();
+    if props.group {
+        classes.push("btn-group");
+    }
+    match props.direction {
+        DropDirection::Down if !props.group => classes.push("dropdown"),
+        DropDirection::Down => (),
+        DropDirection::DownCentered => classes.push("dropdown-center"),
+        DropDirection::Up => classes.push("dropup"),
+        DropDirection::UpCentered => classes.push(classes!("dropup", "dropup-center")),
+        DropDirection::Start => classes.push("dropstart"),
+        DropDirection::End => classes.push("dropend"),
+    }
+    classes.push(props.class.clone());
+
+    let context = DropdownContext {
+        show,
+        set_show,
+        toggle_ref,
+        menu_ref,
+        direction: props.direction,
+    };
+
+    if in_input_group {
+        return html! {
+            <ContextProvider<DropdownContext> {context}>
+                { for props.children.iter() }
+            </ContextProvider<DropdownContext>>
+        };
+    }
+
+    html! {
+        <div class={classes} id={props.id.clone()} {onkeydown}>
+            <ContextProvider<DropdownContext> {context}>
+                { for props.children.iter() }
+            </ContextProvider<DropdownContext>>
+        </div>
+    }
+}
+
+/// Bootstrap doesn't close the menu on clicks on forms inside it
+fn is_form_element(element: &Element) -> bool {
+    matches!(
+        element.tag_name().as_str(),
+        "INPUT" | "SELECT" | "OPTION" | "TEXTAREA" | "FORM"
+    )
+}
+
+/// Move the focus to the next or previous enabled item of the menu
+fn focus_item(menu_ref: &NodeRef, next: bool) {
+    let Some(menu) = menu_ref.cast::<Element>() else {
+        return;
+    };
+    let Ok(nodes) = menu.query_selector_all(".dropdown-item:not(.disabled):not(:disabled)") else {
+        return;
+    };
+    let items: Vec<HtmlElement> = (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into().ok())
+        .collect();
+    if items.is_empty() {
+        return;
+    }
+
+    let focused = gloo_utils::document().active_element();
+    let current = items
+        .iter()
+        .position(|item| focused.as_ref().is_some_and(|focused| focused == item.unchecked_ref::<Element>()));
+    let index = match current {
+        Some(index) if next => (index + 1).min(items.len() - 1),
+        Some(index) => index.saturating_sub(1),
+        None if next => 0,
+        None => items.len() - 1,
+    };
+    let _ = items[index].focus();
+}
+
+/// # Properties of [DropdownToggle]
+#[derive(Properties, Clone, PartialEq)]
+pub struct DropdownToggleProps {
+    /// Text of the button
+    #[prop_or_default]
+    pub text: AttrValue,
+
+    /// Inner components, displayed after the text
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Color of the button
+    #[prop_or(Color::Primary)]
+    pub style: Color,
+
+    /// Outline button
+    #[prop_or_default]
+    pub outline: bool,
+
+    /// Size of the button
+    #[prop_or_default]
+    pub size: ButtonSize,
+
+    /// Button is disabled
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// Render a split button: the main button calls `onclick`, and a separate
+    /// button toggles the menu. The parent [Dropdown] must have `group` set.
+    #[prop_or_default]
+    pub split: bool,
+
+    /// Callback when clicked. For split buttons, called by the main button only.
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
+
+    /// Optional HTML id of the toggle button
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+}
+
+/// # Button toggling the menu of a [Dropdown]
+///
+/// See [DropdownToggleProps] for a listing of properties.
+#[function_component]
+pub fn DropdownToggle(props: &DropdownToggleProps) -> Html {
+    let context = use_context::<DropdownContext>().expect("DropdownToggle must be inside a Dropdown");
+
+    let mut classes = classes!("btn");
+    if props.outline {
+        classes.push(format!("btn-outline-{}", props.style));
+    } else {
+        classes.push(format!("btn-{}", props.style));
+    }
+    match props.size {
+        ButtonSize::Large => classes.push("btn-lg"),
+        ButtonSize::Small => classes.push("btn-sm"),
+        ButtonSize::Normal => (),
+    }
+    classes.push(props.class.clone());
+
+    let toggle = {
+        let onclick = (!props.split).then(|| props.onclick.clone());
+        let set_show = context.set_show.clone();
+        let show = context.show;
+        Callback::from(move |event: MouseEvent| {
+            if let Some(onclick) = &onclick {
+                onclick.emit(event);
+            }
+            set_show.emit(!show);
+        })
+    };
+    let aria_expanded = if context.show { "true" } else { "false" };
+
+    if props.split {
+        html! {
+            <>
+                <button
+                    type="button"
+                    class={classes.clone()}
+                    disabled={props.disabled}
+                    onclick={props.onclick.clone()}
+                    id={props.id.clone()}
+                >
+                    { props.text.clone() }
+                    { for props.children.iter() }
+                </button>
+                <button
+                    type="button"
+                    class={classes!(classes, "dropdown-toggle", "dropdown-toggle-split", context.show.then_some("show"))}
+                    disabled={props.disabled}
+                    aria-expanded={aria_expanded}
+                    onclick={toggle}
+                    ref={context.toggle_ref}
+                >
+                    <span class="visually-hidden">{ "Toggle Dropdown" }</span>
+                </button>
+            </>
+        }
+    } else {
+        html! {
+            <button
+                type="button"
+                class={classes!(classes, "dropdown-toggle", context.show.then_some("show"))}
+                disabled={props.disabled}
+                aria-expanded={aria_expanded}
+                onclick={toggle}
+                ref={context.toggle_ref}
+                id={props.id.clone()}
+            >
+                { props.text.clone() }
+                { for props.children.iter() }
+            </button>
+        }
+    }
+}
+
+/// # Properties of [DropdownMenu]
+#[derive(Properties, Clone, PartialEq)]
+pub struct DropdownMenuProps {
+    /// Items of the menu, typically [DropdownItem], [DropdownHeader] and [DropdownDivider]
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Align the menu on the end of the toggle instead of its start
+    #[prop_or_default]
+    pub align_end: bool,
+
+    /// Dark menu
+    #[prop_or_default]
+    pub dark: bool,
+}
+
+/// # Menu of a [Dropdown]
+///
+/// See [DropdownMenuProps] for a listing of properties.
+#[function_component]
+pub fn DropdownMenu(props: &DropdownMenuProps) -> Html {
+    let context = use_context::<DropdownContext>().expect("DropdownMenu must be inside a Dropdown");
+
+    let placement = match (context.direction, props.align_end) {
+        (DropDirection::Down, false) => Placement::BottomStart,
+        (DropDirection::Down, true) => Placement::BottomEnd,
+        (DropDirection::Up, false) => Placement::TopStart,
+        (DropDirection::Up, true) => Placement::TopEnd,
+        (DropDirection::DownCentered, _) => Placement::Bottom,
+        (DropDirection::UpCentered, _) => Placement::Top,
+        (DropDirection::Start, _) => Placement::LeftStart,
+        (DropDirection::End, _) => Placement::RightStart,
+    };
+
+    let options = use_memo((placement, context.show), |(placement, show)| Options {
+        placement: *placement,
+        modifiers: vec![
+            Modifier::Offset(Offset {
+                skidding: 0,
+                distance: 2,
+            }),
+            // Only follow scrolling and resizing while shown. As the options change,
+            // this also creates the popper once the toggle is rendered.
+            Modifier::Custom {
+                name: "eventListeners".into(),
+                phase: None,
+                enabled: Some(*show),
+                r#fn: None,
+            },
+        ],
+        strategy: Strategy::Absolute,
+    });
+
+    let popper = use_popper(context.toggle_ref.clone(), context.menu_ref.clone(), options).unwrap();
+
+    use_effect_with((context.show, popper.instance.clone()), |(show, popper)| {
+        if *show {
+            let popper = popper.clone();
+            spawn_local(async move {
+                popper.update().await;
+            });
+        }
+    });
+
+    use_effect_with(
+        (context.menu_ref.clone(), popper.state.attributes.popper.clone()),
+        |(menu_ref, attributes)| {
+            menu_ref.apply_attributes(attributes);
+        },
+    );
+
+    let mut classes = classes!("dropdown-menu");
+    if context.show {
+        classes.push("show");
+    }
+    if props.align_end {
+        classes.push("dropdown-menu-end");
+    }
+    if props.dark {
+        classes.push("dropdown-menu-dark");
+    }
+    classes.push(props.class.clone());
+
+    html! {
+        <ul ref={context.menu_ref.clone()} class={classes} style={&popper.state.styles.popper}>
+            { for props.children.iter() }
+        </ul>
+    }
+}
+
+/// # Properties of [DropdownItem]
+#[derive(Properties, Clone, PartialEq)]
+pub struct DropdownItemProps {
+    /// Text of the item
+    #[prop_or_default]
+    pub text: AttrValue,
+
+    /// Inner components, displayed after the text
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// If provided, the item is a link, otherwise a button
+    #[prop_or_default]
+    pub url: Option<AttrValue>,
+
+    /// Callback when clicked
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
+
+    /// Item is the currently active one
+    #[prop_or_default]
+    pub active: bool,
+
+    /// Item is disabled
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// Optional icon
+    #[prop_or_default]
+    pub icon: Option<&'static BI>,
+}
+
+/// # Item of a [DropdownMenu]
+///
+/// See [DropdownItemProps] for a listing of properties.
+#[function_component]
+pub fn DropdownItem(props: &DropdownItemProps) -> Html {
+    let classes = classes!(
+        "dropdown-item",
+        props.active.then_some("active"),
+        props.disabled.then_some("disabled"),
+        props.class.clone()
+    );
+    let content = html! {
+        <>
+            if let Some(icon) = props.icon {
+                {icon}{" "}
+            }
+            { props.text.clone() }
+            { for props.children.iter() }
+        </>
+    };
+
+    html! {
+        <li>
+            if let Some(url) = &props.url {
+                <a
+                    class={classes}
+                    href={url.clone()}
+                    onclick={props.onclick.clone()}
+                    aria-current={props.active.then_some("true")}
+                    aria-disabled={props.disabled.then_some("true")}
+                    tabindex={props.disabled.then_some("-1")}
+                >
+                    { content }
+                </a>
+            } else {
+                <button
+                    type="button"
+                    class={classes}
+                    onclick={props.onclick.clone()}
+                    aria-current={props.active.then_some("true")}
+                    disabled={props.disabled}
+                >
+                    { content }
+                </button>
+            }
+        </li>
+    }
+}
+
+/// # Properties of [DropdownHeader]
+#[derive(Properties, Clone, PartialEq)]
+pub struct DropdownHeaderProps {
+    /// Text of the header
+    #[prop_or_default]
+    pub text: AttrValue,
+
+    /// Inner components, displayed after the text
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Header of a section of a [DropdownMenu]
+///
+/// See [DropdownHeaderProps] for a listing of properties.
+#[function_component]
+pub fn DropdownHeader(props: &DropdownHeaderProps) -> Html {
+    html! {
+        <li>
+            <h6 class={classes!("dropdown-header", props.class.clone())}>
+                { props.text.clone() }
+                { for props.children.iter() }
+            </h6>
+        </li>
+    }
+}
+
+/// # Divider between items of a [DropdownMenu]
+#[function_component]
+pub fn DropdownDivider() -> Html {
+    html! {
+        <li><hr class="dropdown-divider" /></li>
+    }
+}
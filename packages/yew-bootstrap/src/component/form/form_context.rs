@@ -0,0 +1,397 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+use super::*;
+
+/// Values of the fields of a [Form], by name. The value of a `multiple` select is
+/// the values of its selected options separated by commas.
+pub type FormValues = HashMap<AttrValue, AttrValue>;
+
+/// When the validation of a field of a [Form] is displayed. All the fields are
+/// validated when the form is submitted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidationTrigger {
+    /// After each change of the value
+    Input,
+    /// When leaving the field
+    #[default]
+    Blur,
+    /// Only when the form is submitted
+    Submit,
+}
+
+/// Field of a [Form], registered by one or more [FormControl] with the same name
+#[derive(Clone, Default, PartialEq)]
+struct FormField {
+    /// Current value
+    value: AttrValue,
+
+    /// Rules checked on the value
+    validators: Vec<Validator>,
+
+    /// The validation is displayed
+    touched: bool,
+
+    /// Number of controls registered with this name, like the radios of a group
+    controls: usize,
+}
+
+/// Fields of a [Form], by name
+#[derive(Clone, Default, PartialEq)]
+pub(crate) struct FormState {
+    fields: HashMap<AttrValue, FormField>,
+}
+
+/// Change of the fields of a [Form]
+pub(crate) enum FormAction {
+    /// Add a control with the given name, and its value if any
+    Register(AttrValue, Option<AttrValue>),
+    /// Remove a control with the given name
+    Unregister(AttrValue),
+    /// Set the rules checked on a field
+    SetValidators(AttrValue, Vec<Validator>),
+    /// Set the value of a field, displaying its validation if `true`
+    Input(AttrValue, AttrValue, bool),
+    /// Display the validation of a field
+    Touch(AttrValue),
+    /// Display the validation of all the fields
+    TouchAll,
+}
+
+impl Reducible for FormState {
+    type Action = FormAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut fields = self.fields.clone();
+        match action {
+            FormAction::Register(name, value) => {
+                let field = fields.entry(name).or_default();
+                field.controls += 1;
+                if let Some(value) = value {
+                    field.value = value;
+                }
+            },
+            FormAction::Unregister(name) => {
+                if let Some(field) = fields.get_mut(&name) {
+                    field.controls -= 1;
+                    if field.controls == 0 {
+                        fields.remove(&name);
+                    }
+                }
+            },
+            FormAction::SetValidators(name, validators) => {
+                if let Some(field) = fields.get_mut(&name) {
+                    field.validators = validators;
+                }
+            },
+            FormAction::Input(name, value, touch) => {
+                if let Some(field) = fields.get_mut(&name) {
+                    field.value = value;
+                    field.touched |= touch;
+                }
+            },
+            FormAction::Touch(name) => {
+                if let Some(field) = fields.get_mut(&name) {
+                    field.touched = true;
+                }
+            },
+            FormAction::TouchAll => {
+                fields.values_mut().for_each(|field| field.touched = true);
+            },
+        }
+        Rc::new(FormState { fields })
+    }
+}
+
+impl FormState {
+    /// Validation to display for the field with the given name: `None` until it is
+    /// touched or if it has no rule
+    pub(crate) fn validation(&self, name: &AttrValue) -> Option<FormControlValidation> {
+        let field = self.fields.get(name).filter(|field| field.touched && !field.validators.is_empty())?;
+        Some(match validate(&field.value, &field.validators) {
+            Ok(()) => FormControlValidation::Valid(None),
+            Err(message) => FormControlValidation::Invalid(message),
+        })
+    }
+
+    /// Error messages of the invalid fields, by name
+    fn errors(&self) -> HashMap<AttrValue, AttrValue> {
+        self.fields.iter().filter_map(|(name, field)| {
+            validate(&field.value, &field.validators).err().map(|message| (name.clone(), message))
+        }).collect()
+    }
+
+    /// Values of the fields, by name
+    fn values(&self) -> FormValues {
+        self.fields.iter().map(|(name, field)| (name.clone(), field.value.clone())).collect()
+    }
+}
+
+/// Context provided by a [Form], so that its [FormControl] children register their
+/// value and display their validation
+#[derive(Clone, PartialEq)]
+pub(crate) struct FormContext {
+    /// Fields of the form
+    pub(crate) state: UseReducerHandle<FormState>,
+
+    /// When the validation of the fields is displayed
+    pub(crate) trigger: ValidationTrigger,
+}
+
+/// Field of a [Form] used by a control, see [use_form_field]
+pub(crate) struct FormFieldHandle {
+    /// Validation to display: the own validation of the control if set, or the
+    /// validation of the form
+    pub(crate) validation: FormControlValidation,
+
+    /// Set the value entered by the user, displaying the validation depending on the
+    /// trigger of the form. Does nothing outside of a form.
+    pub(crate) update: Callback<AttrValue>,
+
+    /// Display the validation when leaving the control, unless the form is only
+    /// validated on submit
+    pub(crate) onblur: Option<Callback<FocusEvent>>,
+}
+
+/// Register a control with the given `name` in the parent [Form], if any and if
+/// `name` is not empty.
+///
+/// The field keeps `value` (`None` for an unchecked radio) when it changes, or the
+/// value of `select` if it refers to a select element, and is checked with
+/// `validators`. `validation` is the own validation of the control.
+#[hook]
+pub(crate) fn use_form_field(
+    name: AttrValue,
+    value: Option<AttrValue>,
+    select: NodeRef,
+    validators: Vec<Validator>,
+    validation: FormControlValidation,
+) -> FormFieldHandle {
+    let form = use_context::<FormContext>().filter(|_| !name.is_empty());
+
+    // The value of a select is given by its selected options
+    let current_value = {
+        let value = value.clone();
+        move || select.cast::<HtmlSelectElement>().map(|select| select_form_value(&select)).or(value.clone())
+    };
+    {
+        let form = form.clone();
+        let current_value = current_value.clone();
+        use_effect_with(name.clone(), move |name| {
+            let registered = form.map(|form| {
+                form.state.dispatch(FormAction::Register(name.clone(), current_value()));
+                (form, name.clone())
+            });
+            move || {
+                if let Some((form, name)) = registered {
+                    form.state.dispatch(FormAction::Unregister(name));
+                }
+            }
+        });
+    }
+    {
+        // The value may also be changed by the properties, like when it is reset
+        let form = form.clone();
+        use_effect_with((name.clone(), value), move |(name, _)| {
+            if let Some((form, value)) = form.zip(current_value()) {
+                form.state.dispatch(FormAction::Input(name.clone(), value, false));
+            }
+        });
+    }
+    {
+        let form = form.clone();
+        use_effect_with((name.clone(), validators), move |(name, validators)| {
+            if let Some(form) = form {
+                form.state.dispatch(FormAction::SetValidators(name.clone(), validators.clone()));
+            }
+        });
+    }
+
+    let validation = match (validation, &form) {
+        (FormControlValidation::None, Some(form)) => form.state.validation(&name).unwrap_or(FormControlValidation::None),
+        (validation, _) => validation,
+    };
+
+    let update = {
+        let form = form.clone();
+        let name = name.clone();
+        Callback::from(move |value: AttrValue| {
+            if let Some(form) = &form {
+                let touch = form.trigger == ValidationTrigger::Input;
+                form.state.dispatch(FormAction::Input(name.clone(), value, touch));
+            }
+        })
+    };
+
+    let onblur = form.filter(|form| form.trigger != ValidationTrigger::Submit).map(|form| {
+        Callback::from(move |_: FocusEvent| form.state.dispatch(FormAction::Touch(name.clone())))
+    });
+
+    FormFieldHandle { validation, update, onblur }
+}
+
+/// Feedback displayed below a control for its `validation`, and the class of the
+/// control
+pub(crate) fn validation_feedback(validation: FormControlValidation) -> (Option<Html>, Option<&'static str>) {
+    match validation {
+        FormControlValidation::None => (None, None),
+        FormControlValidation::Valid(None) => (None, Some("is-valid")),
+        FormControlValidation::Valid(Some(text)) => (Some(html! {
+            <div class="valid-feedback">{ text }</div>
+        }), Some("is-valid")),
+        FormControlValidation::Invalid(text) => (Some(html! {
+            <div class="invalid-feedback">{ text }</div>
+        }), Some("is-invalid")),
+    }
+}
+
+/// # Properties of [Form]
+#[derive(Properties, Clone, PartialEq)]
+pub struct FormProps {
+    /// Fields of the form, and any other content
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// When the validation of each field is displayed, default
+    /// [ValidationTrigger::Blur]
+    #[prop_or_default]
+    pub trigger: ValidationTrigger,
+
+    /// Called with the values of the fields, by name, when the form is submitted
+    /// and all the fields are valid
+    #[prop_or_default]
+    pub on_submit: Callback<FormValues>,
+
+    /// Called with the error messages of the invalid fields, by name, when the form
+    /// is submitted and some fields are invalid
+    #[prop_or_default]
+    pub on_invalid: Callback<HashMap<AttrValue, AttrValue>>,
+}
+
+/// # Form component
+/// Form tracking the values of its [FormControl] fields and validating them.
+///
+/// Each [FormControl] with a `name` inside the form registers its value, updated
+/// by the user or by its `value` and `checked` properties, and checks it with its
+/// `validators` (see [Validator]). The validation of a field is
+/// displayed when the value changes, when leaving the field or when submitting,
+/// depending on `trigger`, unless the field has its own `validation`.
+///
+/// When submitted, the form displays the validation of all its fields, and calls
+/// `on_submit` with their values only if they are all valid. Otherwise, `on_invalid`
+/// is called with the error messages.
+///
+/// See [FormProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Button;
+/// use yew_bootstrap::component::form::*;
+/// use yew_bootstrap::util::Color;
+///
+/// fn test() -> Html {
+///     let on_submit = Callback::from(|values: FormValues| {
+///         let _username = &values["username"];
+///     });
+///
+///     html!{
+///         <Form {on_submit}>
+///             <FormControl id="username" name="username" ctype={FormControlType::Text} label="Username"
+///                 validators={vec![
+///                     Validator::Required("Choose a username".into()),
+///                     Validator::MinLength(3, "At least 3 characters".into()),
+///                     Validator::Pattern("[a-z0-9_]+".into(), "Only lower case letters, digits and _".into()),
+///                 ]} />
+///             <FormControl id="terms" name="terms" ctype={FormControlType::Checkbox} label="I accept the terms"
+///                 validators={vec![Validator::Required("Accept the terms to continue".into())]} />
+///             <Button style={Color::Primary}>{"Sign up"}</Button>
+///         </Form>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Form(props: &FormProps) -> Html {
+    let state = use_reducer_eq(FormState::default);
+
+    let onsubmit = {
+        let state = state.clone();
+        let on_submit = props.on_submit.clone();
+        let on_invalid = props.on_invalid.clone();
+        Callback::from(move |event: SubmitEvent| {
+            event.prevent_default();
+            state.dispatch(FormAction::TouchAll);
+            let errors = state.errors();
+            if errors.is_empty() {
+                on_submit.emit(state.values());
+            } else {
+                on_invalid.emit(errors);
+            }
+        })
+    };
+
+    let context = FormContext {
+        state,
+        trigger: props.trigger,
+    };
+
+    html! {
+        <form class={props.class.clone()} novalidate={true} {onsubmit}>
+            <ContextProvider<FormContext> {context}>
+                { for props.children.iter() }
+            </ContextProvider<FormContext>>
+        </form>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reduce(state: FormState, actions: Vec<FormAction>) -> Rc<FormState> {
+        actions.into_iter().fold(Rc::new(state), |state, action| state.reduce(action))
+    }
+
+    #[test]
+    fn form_state_validates_touched_fields() {
+        let name = AttrValue::from("name");
+        let state = reduce(FormState::default(), vec![
+            FormAction::Register(name.clone(), Some("".into())),
+            FormAction::SetValidators(name.clone(), vec![Validator::Required("Required".into())]),
+        ]);
+        assert_eq!(state.validation(&name), None);
+        assert_eq!(state.errors(), HashMap::from([(name.clone(), "Required".into())]));
+
+        let state = reduce(Rc::unwrap_or_clone(state), vec![FormAction::Input(name.clone(), "Bob".into(), false)]);
+        assert_eq!(state.validation(&name), None);
+        assert!(state.errors().is_empty());
+
+        let state = reduce(Rc::unwrap_or_clone(state), vec![FormAction::Touch(name.clone())]);
+        assert_eq!(state.validation(&name), Some(FormControlValidation::Valid(None)));
+        assert_eq!(state.values(), HashMap::from([(name.clone(), "Bob".into())]));
+    }
+
+    #[test]
+    fn form_state_counts_controls() {
+        let color = AttrValue::from("color");
+        let state = reduce(FormState::default(), vec![
+            FormAction::Register(color.clone(), None),
+            FormAction::Register(color.clone(), Some("red".into())),
+            FormAction::Register(color.clone(), None),
+            FormAction::Unregister(color.clone()),
+        ]);
+        assert_eq!(state.values(), HashMap::from([(color.clone(), "red".into())]));
+
+        let state = reduce(Rc::unwrap_or_clone(state), vec![
+            FormAction::Unregister(color.clone()),
+            FormAction::Unregister(color.clone()),
+        ]);
+        assert!(state.values().is_empty());
+    }
+}
@@ -1,8 +1,10 @@
+use web_sys::{File, HtmlInputElement, HtmlOptionElement, HtmlSelectElement, HtmlTextAreaElement};
+use wasm_bindgen::JsCast;
 use yew::prelude::*;
 use super::*;
 
 /// Validation type for a form control, with feedback message
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum FormControlValidation {
     /// Form field has not been validated or nothing to show
     None,
@@ -74,7 +76,7 @@ pub struct FormControlProps {
     #[prop_or_default]
     pub floating: bool,
 
-    /// Multiple select, only used for select form input
+    /// Multiple selection, only used for select and file inputs
     #[prop_or_default]
     pub multiple: bool,
 
@@ -87,6 +89,11 @@ pub struct FormControlProps {
     #[prop_or(FormControlValidation::None)]
     pub validation: FormControlValidation,
 
+    /// Rules checked on the value inside a [Form], which displays the validation
+    /// unless `validation` is set. Requires `name`.
+    #[prop_or_default]
+    pub validators: Vec<Validator>,
+
     /// Optional onchange event applied on the input
     /// For a text input, this is called when leaving the input field
     #[prop_or_default]
@@ -101,6 +108,32 @@ pub struct FormControlProps {
     #[prop_or_default]
     pub onclick: Callback<MouseEvent>,
 
+    /// Called with the new value: for text inputs and textarea each time an input
+    /// is received, for a select when the selection changes, and for a radio with
+    /// its `value` when it is selected
+    #[prop_or_default]
+    pub on_value: Callback<String>,
+
+    /// Called when a checkbox is checked or unchecked, or when a radio is checked:
+    /// browsers do not notify the radio unchecked by checking another one of the
+    /// group
+    #[prop_or_default]
+    pub on_checked: Callback<bool>,
+
+    /// Called with the new value of a number or range input each time an input is
+    /// received, `None` if the value is empty or invalid
+    #[prop_or_default]
+    pub on_number: Callback<Option<f64>>,
+
+    /// Called with the selected files of a file input
+    #[prop_or_default]
+    pub on_files: Callback<Vec<File>>,
+
+    /// Called with the values of the selected options of a select, typically
+    /// a `multiple` one
+    #[prop_or_default]
+    pub on_selected: Callback<Vec<String>>,
+
     /// Reference to the [NodeRef] of the form control's underlying `<input>`,
     /// `<select>`, `<textarea>` element.
     ///
@@ -114,6 +147,100 @@ pub struct FormControlProps {
 }
 
 
+/// Values of the selected options of `select`
+fn selected_values(select: &HtmlSelectElement) -> Vec<String> {
+    let options = select.selected_options();
+    (0..options.length())
+        .filter_map(|index| options.item(index))
+        .filter_map(|option| option.dyn_into::<HtmlOptionElement>().ok())
+        .map(|option| option.value())
+        .collect()
+}
+
+/// Value of `select` as registered in a [Form]: the values of the selected options
+/// separated by commas, as a `multiple` select may have several
+pub(crate) fn select_form_value(select: &HtmlSelectElement) -> AttrValue {
+    AttrValue::from(selected_values(select).join(","))
+}
+
+/// Value of the control targeted by `event`, as registered in a [Form]: for a
+/// checkbox, `value` (or "on") if checked and empty otherwise. `None` for an
+/// unchecked radio, as another radio of the group holds the value.
+fn event_value(event: &Event, value: &AttrValue) -> Option<AttrValue> {
+    if let Some(input) = event.target_dyn_into::<HtmlInputElement>() {
+        let checked_value = if value.is_empty() { AttrValue::from("on") } else { value.clone() };
+        match &input.type_()[..] {
+            "checkbox" => Some(if input.checked() { checked_value } else { AttrValue::from("") }),
+            "radio" => input.checked().then_some(checked_value),
+            _ => Some(input.value().into()),
+        }
+    } else if let Some(select) = event.target_dyn_into::<HtmlSelectElement>() {
+        Some(select_form_value(&select))
+    } else {
+        event.target_dyn_into::<HtmlTextAreaElement>().map(|textarea| textarea.value().into())
+    }
+}
+
+/// Typed callbacks of a [FormControl], called from its events
+#[derive(Clone)]
+struct TypedCallbacks {
+    on_value: Callback<String>,
+    on_checked: Callback<bool>,
+    on_number: Callback<Option<f64>>,
+    on_files: Callback<Vec<File>>,
+    on_selected: Callback<Vec<String>>,
+}
+
+impl TypedCallbacks {
+    fn new(props: &FormControlProps) -> Self {
+        Self {
+            on_value: props.on_value.clone(),
+            on_checked: props.on_checked.clone(),
+            on_number: props.on_number.clone(),
+            on_files: props.on_files.clone(),
+            on_selected: props.on_selected.clone(),
+        }
+    }
+
+    /// Call the callbacks of text inputs and textarea, on `input` events
+    fn input(&self, event: &Event) {
+        if let Some(input) = event.target_dyn_into::<HtmlInputElement>() {
+            if let "number" | "range" = &input.type_()[..] {
+                let number = input.value_as_number();
+                self.on_number.emit((!number.is_nan()).then_some(number));
+            }
+            self.on_value.emit(input.value());
+        } else if let Some(textarea) = event.target_dyn_into::<HtmlTextAreaElement>() {
+            self.on_value.emit(textarea.value());
+        }
+    }
+
+    /// Call the callbacks of checkbox, radio, file and select, on `change` events
+    fn change(&self, event: &Event) {
+        if let Some(input) = event.target_dyn_into::<HtmlInputElement>() {
+            match &input.type_()[..] {
+                "checkbox" => self.on_checked.emit(input.checked()),
+                "radio" => {
+                    self.on_checked.emit(input.checked());
+                    if input.checked() {
+                        self.on_value.emit(input.value());
+                    }
+                },
+                "file" => {
+                    let files = input.files().map(|files| {
+                        (0..files.length()).filter_map(|index| files.get(index)).collect()
+                    });
+                    self.on_files.emit(files.unwrap_or_default());
+                },
+                _ => (),
+            }
+        } else if let Some(select) = event.target_dyn_into::<HtmlSelectElement>() {
+            self.on_value.emit(select.value());
+            self.on_selected.emit(selected_values(&select));
+        }
+    }
+}
+
 /// Convert an option (Typically integer) to an AttrValue option
 fn convert_to_string_option<T>(value: &Option<T>) -> Option<AttrValue>
 where T: std::fmt::Display {
@@ -172,6 +299,33 @@ where T: std::fmt::Display {
 /// }
 /// ```
 ///
+/// Use [FormControlType::Decimal] and [FormControlType::DecimalRange] for negative
+/// or decimal values. A range can display its current value and ticks. Inside a
+/// [Form], the value of number and range inputs is checked against their bounds.
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::form::*;
+/// fn test() -> Html {
+///   html! {
+///     <FormControl
+///         id="input-decimal-range"
+///         ctype={
+///             FormControlType::DecimalRange {
+///                 min: -1.0,
+///                 max: 1.0,
+///                 step: Some(0.25),
+///                 ticks: vec![-1.0, 0.0, 1.0],
+///                 show_value: true,
+///             }
+///         }
+///         class="mb-3"
+///         label="Balance"
+///         value="0.5"
+///     />
+///   }
+/// }
+/// ```
+///
 /// Almost all properties are `AttrValue` type, and need to be converted into the
 /// correct format, as required by the input. For example for a DateTime with range
 /// input:
@@ -226,12 +380,59 @@ where T: std::fmt::Display {
 /// only called when the input looses focus, while `oninput` is called each time a key is
 /// pressed.
 ///
-/// In the callback, the target needs to be converted to a descendent of `HtmlElement`
+/// Typed callbacks receive the value without converting the target of the event:
+/// `on_value` for the value as a string, `on_checked` for a checkbox or radio,
+/// `on_number` for a number or range, `on_files` for a file input and `on_selected`
+/// for the options of a select. For example:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::form::*;
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let quantity = use_state(|| None::<f64>);
+///     let sizes = use_state(Vec::<String>::new);
+///
+///     let on_number = {
+///         let quantity = quantity.clone();
+///         Callback::from(move |value: Option<f64>| quantity.set(value))
+///     };
+///     let on_selected = {
+///         let sizes = sizes.clone();
+///         Callback::from(move |values: Vec<String>| sizes.set(values))
+///     };
+///
+///     html! {
+///         <>
+///             <FormControl id="quantity" ctype={ FormControlType::Number { min: Some(1), max: None } }
+///                 label="Quantity" {on_number} />
+///             <FormControl id="sizes" ctype={ FormControlType::Select } label="Sizes" multiple=true {on_selected}>
+///                 <SelectOption key=0 label="Small" value="S" />
+///                 <SelectOption key=1 label="Medium" value="M" />
+///                 <SelectOption key=2 label="Large" value="L" />
+///             </FormControl>
+///             <FormControl id="photos" ctype={ FormControlType::File { accept: vec!["image/*".into()] } }
+///                 label="Photos" multiple=true on_files={ Callback::from(|files: Vec<web_sys::File>| {
+///                     let _count = files.len();
+///                 }) } />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// With the raw events, the target needs to be converted to a descendent of `HtmlElement`
 /// to access the fields (Like `type`, `name` and `value`). All inputs can be converted
 /// to `HtmlInputElement` but `Select` and `TextArea`. This is an example of callback
 /// function to convert to the correct type; `checkbox` is special as the `checked`
 /// property should be used.
 ///
+/// Inside an [InputGroup], the input is rendered without its container, see
+/// [InputGroup] for details.
+///
+/// Inside a [Form], a control with a `name` registers its value in the form, and
+/// its `validators` are checked by the form, see [Form] for details.
+///
 /// Note: `HtmlTextAreaElement` and `HtmlSelectElement` are not enabled by default
 /// and need the feature to be required:
 ///
@@ -281,8 +482,95 @@ where T: std::fmt::Display {
 
 #[function_component]
 pub fn FormControl(props: &FormControlProps) -> Html {
+    let input_group = use_context::<InputGroupContext>();
+    let in_group = input_group.is_some();
+
+    // Inside a Form, a named control registers its value and validators. A number
+    // is also checked against the bounds of the control.
+    let value = match props.ctype {
+        FormControlType::Checkbox if props.checked => Some(AttrValue::from(if props.value.is_empty() { "on" } else { &props.value })),
+        FormControlType::Checkbox => Some(AttrValue::from("")),
+        FormControlType::Radio => props.checked.then(|| props.value.clone()),
+        _ => Some(props.value.clone()),
+    };
+    let validators = use_memo((props.validators.clone(), props.ctype.clone()), |(validators, ctype)| {
+        let mut validators = validators.clone();
+        if ctype.is_number() {
+            let ctype = ctype.clone();
+            validators.push(Validator::custom(move |value| ctype.parse_number(value).map(|_| ())));
+        }
+        validators
+    });
+    let FormFieldHandle { validation, update, onblur } = use_form_field(
+        props.name.clone(),
+        value,
+        props.node_ref.clone(),
+        (*validators).clone(),
+        props.validation.clone(),
+    );
+
+    // Value displayed below a range, from the last input until the value changes
+    let range_value = use_state_eq(|| None::<AttrValue>);
+    {
+        let range_value = range_value.clone();
+        use_effect_with(props.value.clone(), move |_| range_value.set(None));
+    }
+
+    // Events updating the value in the form and calling the typed callbacks, before
+    // calling the callbacks of the control
+    let update = {
+        let value = props.value.clone();
+        move |event: &Event| {
+            if let Some(new_value) = event_value(event, &value) {
+                update.emit(new_value);
+            }
+        }
+    };
+    let typed = TypedCallbacks::new(props);
+    let oninput = {
+        let update = update.clone();
+        let typed = typed.clone();
+        let range_value = range_value.clone();
+        let show_value = matches!(props.ctype, FormControlType::DecimalRange { show_value: true, .. });
+        let oninput = props.oninput.clone();
+        Callback::from(move |event: InputEvent| {
+            update(&event);
+            if let Some(input) = event.target_dyn_into::<HtmlInputElement>().filter(|_| show_value) {
+                range_value.set(Some(input.value().into()));
+            }
+            typed.input(&event);
+            oninput.emit(event);
+        })
+    };
+    let onchange = {
+        let onchange = props.onchange.clone();
+        Callback::from(move |event: Event| {
+            update(&event);
+            typed.change(&event);
+            onchange.emit(event);
+        })
+    };
+    // Inside an InputGroup, the feedback is displayed by the group
+    use_effect_with((props.id.clone(), validation.clone()), move |(id, validation)| {
+        let reset = input_group.map(|group| {
+            group.set_validation.emit((id.clone(), Some(validation.clone())));
+            (group, id.clone())
+        });
+        move || {
+            if let Some((group, id)) = reset {
+                group.set_validation.emit((id, None));
+            }
+        }
+    });
+
+    // Inside an InputGroup, the input is a direct child of the group and is
+    // labelled with `aria-label`
+    let bare = in_group && !props.floating;
+    let aria_label = if bare { props.label.clone() } else { None };
+
     let label = match props.label.clone() {
         None => None,
+        Some(_) if bare => None,
         Some(text) => {
             let class = if props.floating { None } else { Some("form-label") };
             Some(html! {
@@ -291,20 +579,15 @@ pub fn FormControl(props: &FormControlProps) -> Html {
         }
     };
 
-    let help = props.help.as_ref().map(|text| html! {
+    let help = props.help.as_ref().filter(|_| !in_group).map(|text| html! {
         <div class="form-text">{ text.clone() }</div>
     });
 
-    let (validation, validation_class) = match props.validation.clone() {
-        FormControlValidation::None => (None, None),
-        FormControlValidation::Valid(None) => (None, Some("is-valid")),
-        FormControlValidation::Valid(Some(text)) => (Some(html! {
-            <div class="valid-feedback"> { text.clone() }</div>
-        }), Some("is-valid")),
-        FormControlValidation::Invalid(text) => (Some(html! {
-            <div class="invalid-feedback"> { text.clone() }</div>
-        }), Some("is-invalid")),
-    };
+    let (mut validation, validation_class) = validation_feedback(validation);
+
+    if in_group {
+        validation = None;
+    }
 
     let pattern = match &props.ctype {
         FormControlType::Email{ pattern } => pattern,
@@ -325,36 +608,46 @@ pub fn FormControl(props: &FormControlProps) -> Html {
                 classes.push("form-floating");
             }
 
-            let input_classes = classes!("form-control", validation_class);
+            let input_classes = classes!("form-control", validation_class, bare.then(|| props.class.clone()));
 
             let cols_str = convert_to_string_option(cols);
             let rows_str = convert_to_string_option(rows);
             let (label_before, label_after) =
                 if props.floating { (None, label) } else { (label, None) };
 
+            let input = html! {
+                <textarea
+                    class={ input_classes }
+                    id={ props.id.clone() }
+                    name={ props.name.clone() }
+                    cols={ cols_str }
+                    rows={ rows_str }
+                    placeholder={ placeholder }
+                    value={ props.value.clone() }
+                    disabled={ props.disabled }
+                    oninput={ oninput.clone() }
+                    onchange={ onchange.clone() }
+                    onblur={ onblur.clone() }
+                    onclick={ props.onclick.clone() }
+                    required={ props.required }
+                    autocomplete={ props.autocomplete.to_str() }
+                    aria-label={ aria_label }
+                    ref={ props.node_ref.clone() }
+                />
+            };
+
             html! {
-                <div class={ classes }>
-                    { label_before }
-                    <textarea
-                        class={ input_classes }
-                        id={ props.id.clone() }
-                        name={ props.name.clone() }
-                        cols={ cols_str }
-                        rows={ rows_str }
-                        placeholder={ placeholder }
-                        value={ props.value.clone() }
-                        disabled={ props.disabled }
-                        oninput={props.oninput.clone() }
-                        onchange={ props.onchange.clone() }
-                        onclick={ props.onclick.clone() }
-                        required={ props.required }
-                        autocomplete={ props.autocomplete.to_str() }
-                        ref={ props.node_ref.clone() }
-                    />
-                    { label_after }
-                    { help }
-                    { validation }
-                </div>
+                if bare {
+                    { input }
+                } else {
+                    <div class={ classes }>
+                        { label_before }
+                        { input }
+                        { label_after }
+                        { help }
+                        { validation }
+                    </div>
+                }
             }
         },
         FormControlType::Select => {
@@ -363,57 +656,82 @@ pub fn FormControl(props: &FormControlProps) -> Html {
                 classes.push("form-floating");
             }
 
-            let input_classes = classes!("form-select", validation_class);
+            let input_classes = classes!("form-select", validation_class, bare.then(|| props.class.clone()));
 
             let (label_before, label_after) =
                 if props.floating { (None, label) } else { (label, None) };
 
+            let input = html! {
+                <select
+                    class={ input_classes }
+                    id={ props.id.clone()}
+                    name={ props.name.clone() }
+                    disabled={ props.disabled }
+                    multiple={ props.multiple }
+                    onchange={ onchange.clone() }
+                    onblur={ onblur.clone() }
+                    onclick={ props.onclick.clone() }
+                    required={ props.required }
+                    aria-label={ aria_label }
+                    ref={ props.node_ref.clone() }
+                >
+                    { for props.children.clone() }
+                </select>
+            };
+
             html! {
-                <div class={ classes }>
-                    { label_before }
-                    <select
-                        class={ input_classes }
-                        id={ props.id.clone()}
-                        name={ props.name.clone() }
-                        disabled={ props.disabled }
-                        onchange={ props.onchange.clone() }
-                        onclick={ props.onclick.clone() }
-                        required={ props.required }
-                        ref={ props.node_ref.clone() }
-                    >
-                        { for props.children.clone() }
-                    </select>
-                    { label_after }
-                    { help }
-                    { validation }
-                </div>
+                if bare {
+                    { input }
+                } else {
+                    <div class={ classes }>
+                        { label_before }
+                        { input }
+                        { label_after }
+                        { help }
+                        { validation }
+                    </div>
+                }
             }
         },
         FormControlType::Checkbox | FormControlType::Radio => {
             let mut classes = classes!("form-check");
             classes.push(props.class.clone());
 
-            let input_classes = classes!("form-check-input", validation_class);
+            let input_classes = classes!("form-check-input", validation_class, in_group.then_some("mt-0"));
+
+            // Inside an InputGroup, the checkbox or radio is an addon
+            let aria_label = if in_group { props.label.clone() } else { None };
+            let input = html! {
+                <input
+                    type={ props.ctype.to_str() }
+                    class={ input_classes }
+                    id={ props.id.clone() }
+                    name={ props.name.clone() }
+                    checked={ props.checked }
+                    disabled={ props.disabled }
+                    value={ props.value.clone() }
+                    onchange={ onchange.clone() }
+                    onblur={ onblur.clone() }
+                    onclick={ props.onclick.clone() }
+                    required={ props.required }
+                    aria-label={ aria_label }
+                    ref={ props.node_ref.clone() }
+                />
+            };
 
             html! {
-                <div class={ classes }>
-                    <input
-                        type={ props.ctype.to_str() }
-                        class={ input_classes }
-                        id={ props.id.clone() }
-                        name={ props.name.clone() }
-                        checked={ props.checked }
-                        disabled={ props.disabled }
-                        value={ props.value.clone() }
-                        onchange={ props.onchange.clone() }
-                        onclick={ props.onclick.clone() }
-                        required={ props.required }
-                        ref={ props.node_ref.clone() }
-                    />
-                    { label }
-                    { help }
-                    { validation}
-                </div>
+                if in_group {
+                    <div class={ classes!("input-group-text", props.class.clone()) }>
+                        { input }
+                    </div>
+                } else {
+                    <div class={ classes }>
+                        { input }
+                        { label }
+                        { help }
+                        { validation}
+                    </div>
+                }
             }
         },
         _ => {
@@ -421,6 +739,8 @@ pub fn FormControl(props: &FormControlProps) -> Html {
             let mut max_str = None;
             let mut step_str = None;
             let mut accept_str = None;
+            let mut ticks = None;
+            let mut output = None;
             match &props.ctype {
                 FormControlType::Number { min, max } => {
                     min_str = convert_to_string_option(min);
@@ -431,6 +751,31 @@ pub fn FormControl(props: &FormControlProps) -> Html {
                     max_str = Some(AttrValue::from(max.to_string()));
                     step_str = convert_to_string_option(step);
                 },
+                FormControlType::Decimal { min, max, step } => {
+                    min_str = convert_to_string_option(min);
+                    max_str = convert_to_string_option(max);
+                    step_str = Some(convert_to_string_option(step).unwrap_or(AttrValue::from("any")));
+                },
+                FormControlType::DecimalRange { min, max, step, ticks: tick_values, show_value } => {
+                    min_str = Some(AttrValue::from(min.to_string()));
+                    max_str = Some(AttrValue::from(max.to_string()));
+                    step_str = Some(convert_to_string_option(step).unwrap_or(AttrValue::from("any")));
+                    if !tick_values.is_empty() {
+                        ticks = Some(html! {
+                            <datalist id={ format!("{}-ticks", props.id) }>
+                                { for tick_values.iter().map(|tick| html! { <option value={ tick.to_string() } /> }) }
+                            </datalist>
+                        });
+                    }
+                    if *show_value {
+                        let value = (*range_value).clone()
+                            .or_else(|| Some(props.value.clone()).filter(|value| !value.is_empty()))
+                            .or_else(|| convert_to_string_option(&props.ctype.default_range_value()));
+                        output = Some(html! {
+                            <output class="form-text d-block" for={ props.id.clone() }>{ value.unwrap_or_default() }</output>
+                        });
+                    }
+                },
                 FormControlType::DateMinMax { min, max } |
                 FormControlType::DatetimeMinMax { min, max } |
                 FormControlType::TimeMinMax { min, max } => {
@@ -438,7 +783,7 @@ pub fn FormControl(props: &FormControlProps) -> Html {
                     max_str = max.clone();
                 },
                 FormControlType::File { accept } => {
-                    let accept_vec : Vec<String> = accept.clone().iter().cloned().map(
+                    let accept_vec : Vec<String> = accept.iter().map(
                         move |value| { value.to_string() }
                     ).collect();
                     accept_str = Some(accept_vec.join(", "));
@@ -451,38 +796,53 @@ pub fn FormControl(props: &FormControlProps) -> Html {
                 classes.push("form-floating");
             }
 
-            let input_classes = classes!("form-control", validation_class);
+            let input_classes = classes!("form-control", validation_class, bare.then(|| props.class.clone()));
 
             let (label_before, label_after) =
                 if props.floating { (None, label) } else { (label, None) };
 
+            let input = html! {
+                <input
+                    type={ props.ctype.to_str() }
+                    class={ input_classes }
+                    id={ props.id.clone() }
+                    name={ props.name.clone() }
+                    value={ props.value.clone() }
+                    pattern={ pattern }
+                    accept={ accept_str }
+                    multiple={ matches!(props.ctype, FormControlType::File { .. }) && props.multiple }
+                    placeholder={ placeholder }
+                    min={ min_str }
+                    max={ max_str }
+                    step={ step_str }
+                    list={ ticks.as_ref().map(|_| format!("{}-ticks", props.id)) }
+                    disabled={ props.disabled }
+                    onchange={ onchange.clone() }
+                    onblur={ onblur.clone() }
+                    onclick={ props.onclick.clone() }
+                    oninput={ oninput.clone() }
+                    required={ props.required }
+                    autocomplete={ props.autocomplete.to_str() }
+                    aria-label={ aria_label }
+                    ref={ props.node_ref.clone() }
+                />
+            };
+
             html! {
-                <div class={ classes }>
-                    { label_before }
-                    <input
-                        type={ props.ctype.to_str() }
-                        class={ input_classes }
-                        id={ props.id.clone() }
-                        name={ props.name.clone() }
-                        value={ props.value.clone() }
-                        pattern={ pattern }
-                        accept={ accept_str }
-                        placeholder={ placeholder }
-                        min={ min_str }
-                        max={ max_str }
-                        step={ step_str }
-                        disabled={ props.disabled }
-                        onchange={ props.onchange.clone() }
-                        onclick={ props.onclick.clone() }
-                        oninput={ props.oninput.clone() }
-                        required={ props.required }
-                        autocomplete={ props.autocomplete.to_str() }
-                        ref={ props.node_ref.clone() }
-                    />
-                    { label_after }
-                    { help }
-                    { validation }
-                </div>
+                if bare {
+                    { input }
+                    { ticks }
+                } else {
+                    <div class={ classes }>
+                        { label_before }
+                        { input }
+                        { ticks }
+                        { label_after }
+                        { output }
+                        { help }
+                        { validation }
+                    </div>
+                }
             }
         }
     }
@@ -19,6 +19,13 @@ pub enum FormControlType {
     Number { min: Option<u32>, max: Option<u32> },
     /// Range selection from min to max, with optional step
     Range { min: i32, max: i32, step: Option<u32> },
+    /// Number with optional min, max and step, accepting negative and decimal values.
+    /// Any value is accepted without step.
+    Decimal { min: Option<f64>, max: Option<f64>, step: Option<f64> },
+    /// Range selection from min to max with optional step, accepting negative and
+    /// decimal values. `ticks` are marks displayed along the range, and `show_value`
+    /// displays the current value below it.
+    DecimalRange { min: f64, max: f64, step: Option<f64>, ticks: Vec<f64>, show_value: bool },
 
     /// Select to select one of more options. It typically contains [crate::component::form::SelectOption]
     /// or [crate::component::form::SelectOptgroup] children
@@ -60,10 +67,10 @@ impl FormControlType {
             Self::Email { .. } => "email",
             Self::File { .. } => "file",
             Self::Hidden => "hidden",
-            Self::Number { .. } => "number",
+            Self::Number { .. } | Self::Decimal { .. } => "number",
             Self::Password { .. } => "password",
             Self::Radio => "radio",
-            Self::Range { .. } => "range",
+            Self::Range { .. } | Self::DecimalRange { .. } => "range",
             Self::Select => "select",
             Self::Text => "text",
             Self::TextArea { .. } => "",
@@ -73,4 +80,107 @@ impl FormControlType {
 
         AttrValue::from(value)
     }
+
+    /// Bounds of a number or range control: min, max and step. `None` for other types
+    /// of control.
+    fn number_bounds(&self) -> Option<(Option<f64>, Option<f64>, Option<f64>)> {
+        match self {
+            Self::Number { min, max } => Some((min.map(f64::from), max.map(f64::from), Some(1.0))),
+            Self::Range { min, max, step } => {
+                Some((Some(f64::from(*min)), Some(f64::from(*max)), Some(step.map_or(1.0, f64::from))))
+            },
+            Self::Decimal { min, max, step } => Some((*min, *max, *step)),
+            Self::DecimalRange { min, max, step, .. } => Some((Some(*min), Some(*max), *step)),
+            _ => None,
+        }
+    }
+
+    /// Is this a number or range control?
+    pub(crate) fn is_number(&self) -> bool {
+        self.number_bounds().is_some()
+    }
+
+    /// Parse the value of a number or range control, checking it against the min,
+    /// max and step of the control. Returns `None` for an empty value, or the error
+    /// message if the value is invalid.
+    pub fn parse_number(&self, value: &str) -> Result<Option<f64>, AttrValue> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(None);
+        }
+        let number = value.parse::<f64>().ok().filter(|number| number.is_finite())
+            .ok_or_else(|| AttrValue::from("Enter a number"))?;
+
+        let (min, max, step) = self.number_bounds().unwrap_or_default();
+        if let Some(min) = min.filter(|min| number < *min) {
+            return Err(AttrValue::from(format!("Enter a number greater than or equal to {min}")));
+        }
+        if let Some(max) = max.filter(|max| number > *max) {
+            return Err(AttrValue::from(format!("Enter a number less than or equal to {max}")));
+        }
+        if let Some(step) = step.filter(|step| *step > 0.0) {
+            // Steps start at min, with some tolerance for floating point errors
+            let steps = (number - min.unwrap_or(0.0)) / step;
+            if (steps - steps.round()).abs() > 1e-9 * steps.abs().max(1.0) {
+                return Err(AttrValue::from(format!("Enter a number in steps of {step}")));
+            }
+        }
+        Ok(Some(number))
+    }
+
+    /// Value of a range control with an empty value, as chosen by the browser: the
+    /// middle of the range, rounded to the step. `None` for other types of control.
+    pub fn default_range_value(&self) -> Option<f64> {
+        let (min, max, step) = match self {
+            Self::Range { .. } | Self::DecimalRange { .. } => self.number_bounds()?,
+            _ => return None,
+        };
+        let (min, max) = (min.unwrap_or_default(), max.unwrap_or_default());
+        if max < min {
+            return Some(min);
+        }
+        let middle = min + (max - min) / 2.0;
+        Some(match step.filter(|step| *step > 0.0) {
+            Some(step) => (min + ((middle - min) / step).round() * step).min(max),
+            None => middle,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_number_checks_bounds() {
+        let number = FormControlType::Number { min: Some(10), max: Some(20) };
+        assert_eq!(number.parse_number(""), Ok(None));
+        assert_eq!(number.parse_number(" 12 "), Ok(Some(12.0)));
+        assert_eq!(number.parse_number("abc"), Err("Enter a number".into()));
+        assert_eq!(number.parse_number("5"), Err("Enter a number greater than or equal to 10".into()));
+        assert_eq!(number.parse_number("21"), Err("Enter a number less than or equal to 20".into()));
+        assert_eq!(number.parse_number("12.5"), Err("Enter a number in steps of 1".into()));
+
+        let decimal = FormControlType::Decimal { min: Some(-1.5), max: None, step: Some(0.1) };
+        assert_eq!(decimal.parse_number("-1.5"), Ok(Some(-1.5)));
+        assert_eq!(decimal.parse_number("0.3"), Ok(Some(0.3)));
+        assert_eq!(decimal.parse_number("0.35"), Err("Enter a number in steps of 0.1".into()));
+        assert_eq!(decimal.parse_number("-2"), Err("Enter a number greater than or equal to -1.5".into()));
+        assert_eq!(decimal.parse_number("inf"), Err("Enter a number".into()));
+
+        let any = FormControlType::Decimal { min: None, max: None, step: None };
+        assert_eq!(any.parse_number("2.71"), Ok(Some(2.71)));
+        assert_eq!(FormControlType::Text.parse_number("2.5"), Ok(Some(2.5)));
+    }
+
+    #[test]
+    fn default_range_value_is_middle_step() {
+        let range = FormControlType::Range { min: 0, max: 10, step: Some(3) };
+        assert_eq!(range.default_range_value(), Some(6.0));
+        let range = FormControlType::DecimalRange { min: -1.0, max: 0.0, step: None, ticks: vec![], show_value: true };
+        assert_eq!(range.default_range_value(), Some(-0.5));
+        let range = FormControlType::DecimalRange { min: 0.0, max: 1.0, step: Some(0.25), ticks: vec![], show_value: true };
+        assert_eq!(range.default_range_value(), Some(0.5));
+        assert_eq!(FormControlType::Text.default_range_value(), None);
+    }
 }
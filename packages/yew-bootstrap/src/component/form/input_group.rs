@@ -0,0 +1,181 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+use super::*;
+
+/// Size of an [InputGroup]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputGroupSize {
+    Large,
+    #[default]
+    Normal,
+    Small,
+}
+
+/// Context provided by an [InputGroup], so that its [FormControl] children report
+/// their validation to it
+#[derive(Clone, PartialEq)]
+pub(crate) struct InputGroupContext {
+    /// Set (`Some`) or clear (`None`) the validation of the control with the given id
+    pub(crate) set_validation: Callback<(AttrValue, Option<FormControlValidation>)>,
+}
+
+/// Validation of the controls of an [InputGroup], by id
+#[derive(Default, PartialEq)]
+struct GroupValidation(Vec<(AttrValue, FormControlValidation)>);
+
+impl Reducible for GroupValidation {
+    type Action = (AttrValue, Option<FormControlValidation>);
+
+    fn reduce(self: Rc<Self>, (id, validation): Self::Action) -> Rc<Self> {
+        let mut controls: Vec<_> = self.0.iter().filter(|(other, _)| *other != id).cloned().collect();
+        if let Some(validation) = validation {
+            controls.push((id, validation));
+        }
+        Rc::new(Self(controls))
+    }
+}
+
+/// # Properties of [InputGroup]
+#[derive(Properties, Clone, PartialEq)]
+pub struct InputGroupProps {
+    /// Inner components: [FormControl], [InputGroupText],
+    /// [Button][crate::component::Button] or [Dropdown][crate::component::Dropdown]
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Size of all the controls of the group
+    #[prop_or_default]
+    pub size: InputGroupSize,
+
+    /// Keep the controls on a single line, instead of wrapping them
+    #[prop_or_default]
+    pub nowrap: bool,
+}
+
+/// # Input group
+/// Extends a [FormControl] with text, icons, buttons or dropdowns on either side.
+///
+/// Inside a group, a [FormControl] renders its input as a direct child of the group,
+/// its `label` is used as the `aria-label` of the input (unless `floating` is set),
+/// and its `help` is not displayed. The validation feedback is displayed below the
+/// group. Checkboxes and radios are rendered as addons.
+///
+/// See [InputGroupProps] for a listing of properties.
+///
+/// ## Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::Button;
+/// use yew_bootstrap::component::form::*;
+/// use yew_bootstrap::util::Color;
+///
+/// fn test() -> Html {
+///     html!{
+///         <InputGroup class="mb-3">
+///             <InputGroupText>{"@"}</InputGroupText>
+///             <FormControl id="username" ctype={FormControlType::Text} label="Username" placeholder="Username"
+///                 validation={FormControlValidation::Invalid("Please choose a username.".into())} />
+///             <Button style={Color::Secondary} outline={true}>{"Check"}</Button>
+///         </InputGroup>
+///     }
+/// }
+/// ```
+///
+/// With a checkbox addon and a dropdown, in a small group:
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Dropdown, DropdownItem, DropdownMenu, DropdownToggle};
+/// use yew_bootstrap::component::form::*;
+/// use yew_bootstrap::util::Color;
+///
+/// fn test() -> Html {
+///     html!{
+///         <InputGroup size={InputGroupSize::Small}>
+///             <FormControl id="enabled" ctype={FormControlType::Checkbox} label="Enabled" />
+///             <FormControl id="amount" ctype={FormControlType::Text} label="Amount" />
+///             <Dropdown>
+///                 <DropdownToggle style={Color::Secondary} outline={true} text="Currency" />
+///                 <DropdownMenu align_end={true}>
+///                     <DropdownItem text="EUR" />
+///                     <DropdownItem text="USD" />
+///                 </DropdownMenu>
+///             </Dropdown>
+///         </InputGroup>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn InputGroup(props: &InputGroupProps) -> Html {
+    let validation = use_reducer_eq(GroupValidation::default);
+    let context = InputGroupContext {
+        set_validation: {
+            let validation = validation.dispatcher();
+            Callback::from(move |action| validation.dispatch(action))
+        },
+    };
+
+    let feedback: Vec<Html> = validation.0.iter().filter_map(|(_, validation)| match validation {
+        FormControlValidation::None | FormControlValidation::Valid(None) => None,
+        FormControlValidation::Valid(Some(text)) => Some(html! {
+            <div class="valid-feedback d-block">{ text.clone() }</div>
+        }),
+        FormControlValidation::Invalid(text) => Some(html! {
+            <div class="invalid-feedback d-block">{ text.clone() }</div>
+        }),
+    }).collect();
+
+    let mut classes = classes!("input-group");
+    match props.size {
+        InputGroupSize::Large => classes.push("input-group-lg"),
+        InputGroupSize::Normal => (),
+        InputGroupSize::Small => classes.push("input-group-sm"),
+    }
+    if props.nowrap {
+        classes.push("flex-nowrap");
+    }
+    if !feedback.is_empty() {
+        classes.push("has-validation");
+    }
+    classes.push(props.class.clone());
+
+    html! {
+        <div class={classes}>
+            <ContextProvider<InputGroupContext> {context}>
+                { for props.children.iter() }
+            </ContextProvider<InputGroupContext>>
+            { for feedback }
+        </div>
+    }
+}
+
+/// # Properties of [InputGroupText]
+#[derive(Properties, Clone, PartialEq)]
+pub struct InputGroupTextProps {
+    /// Text, icon or other content
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Text of an [InputGroup]
+/// Text or icon displayed before or after the controls of an [InputGroup].
+///
+/// See [InputGroupTextProps] for a listing of properties, and [InputGroup] for an
+/// example.
+#[function_component]
+pub fn InputGroupText(props: &InputGroupTextProps) -> Html {
+    html! {
+        <span class={classes!("input-group-text", props.class.clone())}>
+            { for props.children.iter() }
+        </span>
+    }
+}
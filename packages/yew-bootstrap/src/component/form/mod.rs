@@ -2,8 +2,22 @@ mod form_type;
 mod form_control;
 mod select_option;
 mod form_autocomplete;
+mod input_group;
+mod validator;
+mod form_context;
+mod range_slider;
+#[cfg(feature = "derive")]
+mod struct_form;
 
 pub use form_type::*;
 pub use form_control::*;
 pub use select_option::*;
-pub use form_autocomplete::*;
\ No newline at end of file
+pub use form_autocomplete::*;
+pub use input_group::*;
+pub use validator::*;
+pub use form_context::*;
+pub use range_slider::*;
+#[cfg(feature = "derive")]
+pub use struct_form::*;
+#[cfg(feature = "derive")]
+pub use yew_bootstrap_derive::{BootstrapForm, FormValue};
@@ -0,0 +1,311 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::*;
+use crate::util::{include_style_once, RANGE_SLIDER_STYLE};
+
+/// Thumb of a [RangeSlider]
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Thumb {
+    Low,
+    High,
+}
+
+/// Clamp `value` between `min` and `max`, and round it to the closest step from `min`
+fn snap(value: f64, min: f64, max: f64, step: Option<f64>) -> f64 {
+    let value = value.max(min).min(max);
+    match step.filter(|step| *step > 0.0) {
+        Some(step) => {
+            // The last step may be before max
+            let steps = ((value - min) / step).round().min(((max - min) / step).floor());
+            // Rounded to remove floating point errors like 0.30000000000000004
+            ((min + steps * step) * 1e10).round() / 1e10
+        },
+        None => value,
+    }
+}
+
+/// Range with both values snapped, in increasing order
+fn normalize(range: (f64, f64), min: f64, max: f64, step: Option<f64>) -> (f64, f64) {
+    let (low, high) = (snap(range.0, min, max, step), snap(range.1, min, max, step));
+    (low.min(high), low.max(high))
+}
+
+/// New range when moving `thumb` to `value`, the thumbs not crossing each other
+fn move_thumb(range: (f64, f64), thumb: Thumb, value: f64) -> (f64, f64) {
+    match thumb {
+        Thumb::Low => (value.min(range.1), range.1),
+        Thumb::High => (range.0, value.max(range.0)),
+    }
+}
+
+/// Position of `value` between `min` and `max`, in percent
+fn percent(value: f64, min: f64, max: f64) -> f64 {
+    if max > min { (value - min) / (max - min) * 100.0 } else { 0.0 }
+}
+
+/// Value of a [RangeSlider] as registered in a [Form]: both values separated by a
+/// comma, like `10,25.5`
+fn form_value(range: (f64, f64)) -> AttrValue {
+    AttrValue::from(format!("{},{}", range.0, range.1))
+}
+
+/// Parse the value of a [RangeSlider] registered in a [Form], like `10,25.5`
+///
+/// ```rust
+/// use yew_bootstrap::component::form::parse_range_value;
+///
+/// assert_eq!(parse_range_value("10,25.5"), Some((10.0, 25.5)));
+/// assert_eq!(parse_range_value("10"), None);
+/// ```
+pub fn parse_range_value(value: &str) -> Option<(f64, f64)> {
+    let (low, high) = value.split_once(',')?;
+    Some((low.trim().parse().ok()?, high.trim().parse().ok()?))
+}
+
+/// # Properties of [RangeSlider]
+#[derive(Properties, Clone, PartialEq)]
+pub struct RangeSliderProps {
+    /// Id of the slider, the thumbs having ids `{id}-low` and `{id}-high`
+    pub id: AttrValue,
+
+    /// CSS class
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Optional label for the slider
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+
+    /// Label of the thumb selecting the lower value, for assistive technologies
+    #[prop_or(AttrValue::from("Minimum"))]
+    pub low_label: AttrValue,
+
+    /// Label of the thumb selecting the higher value, for assistive technologies
+    #[prop_or(AttrValue::from("Maximum"))]
+    pub high_label: AttrValue,
+
+    /// Optional help text
+    #[prop_or_default]
+    pub help: Option<AttrValue>,
+
+    /// Name of the field inside a [Form], whose value is both values separated by
+    /// a comma, see [parse_range_value]
+    #[prop_or_default]
+    pub name: AttrValue,
+
+    /// Lowest value of the slider, default 0
+    #[prop_or(0.0)]
+    pub min: f64,
+
+    /// Highest value of the slider, default 100
+    #[prop_or(100.0)]
+    pub max: f64,
+
+    /// Step between the values, any value is accepted if `None`
+    #[prop_or_default]
+    pub step: Option<f64>,
+
+    /// Selected range. When `None`, the whole range is initially selected and the
+    /// selection is kept by the component.
+    #[prop_or_default]
+    pub value: Option<(f64, f64)>,
+
+    /// Called with the selected range each time a thumb is moved
+    #[prop_or_default]
+    pub on_change: Callback<(f64, f64)>,
+
+    /// Display the selected range below the slider
+    #[prop_or_default]
+    pub show_value: bool,
+
+    /// Disabled if true
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// Form validation feedback
+    #[prop_or(FormControlValidation::None)]
+    pub validation: FormControlValidation,
+
+    /// Rules checked on the value inside a [Form], which displays the validation
+    /// unless `validation` is set. Requires `name`.
+    #[prop_or_default]
+    pub validators: Vec<Validator>,
+}
+
+/// # Range slider component
+/// Slider with two thumbs selecting a range between `min` and `max`, styled like a
+/// Bootstrap range. The thumbs are moved with the pointer or the keyboard, snapping
+/// to `step`, and cannot cross each other.
+///
+/// Inside a [Form], a slider with a `name` registers its value and validators like
+/// a [FormControl]: the value is both values separated by a comma, see
+/// [parse_range_value].
+///
+/// See [RangeSliderProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::form::*;
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let price = use_state(|| (20.0, 80.0));
+///
+///     let on_change = {
+///         let price = price.clone();
+///         Callback::from(move |range: (f64, f64)| price.set(range))
+///     };
+///
+///     html! {
+///         <RangeSlider
+///             id="price"
+///             label="Price"
+///             min={ 0.0 }
+///             max={ 200.0 }
+///             step={ 5.0 }
+///             value={ *price }
+///             {on_change}
+///             show_value=true
+///         />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn RangeSlider(props: &RangeSliderProps) -> Html {
+    use_effect_with((), |_| include_style_once("yew-bootstrap-range-slider", RANGE_SLIDER_STYLE));
+
+    let (min, max, step) = (props.min, props.max, props.step);
+    let range = use_state_eq(|| normalize(props.value.unwrap_or((min, max)), min, max, step));
+    {
+        let range = range.clone();
+        use_effect_with((props.value, min, max, step), move |(value, min, max, step)| {
+            range.set(normalize(value.unwrap_or(*range), *min, *max, *step));
+        });
+    }
+
+    // Inside a Form, a named slider registers its value and validators
+    let FormFieldHandle { validation, update, onblur } = use_form_field(
+        props.name.clone(),
+        Some(form_value(*range)),
+        NodeRef::default(),
+        props.validators.clone(),
+        props.validation.clone(),
+    );
+
+    let oninput = |thumb: Thumb| {
+        let range = range.clone();
+        let update = update.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |event: InputEvent| {
+            let Some(input) = event.target_dyn_into::<HtmlInputElement>() else {
+                return;
+            };
+            let value = snap(input.value_as_number(), min, max, step);
+            let new_range = move_thumb(*range, thumb, value);
+
+            // The thumb stops at the other one
+            let value = if thumb == Thumb::Low { new_range.0 } else { new_range.1 };
+            input.set_value(&value.to_string());
+
+            update.emit(form_value(new_range));
+            range.set(new_range);
+            on_change.emit(new_range);
+        })
+    };
+
+    let (low, high) = *range;
+    let step_str = AttrValue::from(step.map_or(String::from("any"), |step| step.to_string()));
+    let fill_style = format!(
+        "left: {}%; width: {}%;",
+        percent(low, min, max),
+        percent(high, min, max) - percent(low, min, max),
+    );
+    // When both thumbs are at the end, the low one must stay reachable
+    let low_style = (percent(low, min, max) > 50.0).then_some("z-index: 3;");
+
+    let (feedback, validation_class) = validation_feedback(validation);
+
+    let label = props.label.clone().map(|text| html! {
+        <label for={ format!("{}-low", props.id) } class="form-label">{ text }</label>
+    });
+    let output = props.show_value.then(|| html! {
+        <output class="form-text d-block" for={ format!("{}-low {}-high", props.id, props.id) }>
+            { format!("{low} – {high}") }
+        </output>
+    });
+    let help = props.help.clone().map(|text| html! {
+        <div class="form-text">{ text }</div>
+    });
+
+    let thumb = |thumb: Thumb, value: f64| {
+        let (suffix, aria_label, style) = match thumb {
+            Thumb::Low => ("low", props.low_label.clone(), low_style),
+            Thumb::High => ("high", props.high_label.clone(), None),
+        };
+        html! {
+            <input
+                type="range"
+                class="form-range"
+                id={ format!("{}-{suffix}", props.id) }
+                min={ min.to_string() }
+                max={ max.to_string() }
+                step={ step_str.clone() }
+                value={ value.to_string() }
+                disabled={ props.disabled }
+                aria-label={ aria_label }
+                style={ style }
+                oninput={ oninput(thumb) }
+                onblur={ onblur.clone() }
+            />
+        }
+    };
+
+    html! {
+        <div class={ props.class.clone() }>
+            { label }
+            <div class={ classes!("range-slider", validation_class, props.disabled.then_some("disabled")) }>
+                <div class="range-slider-track" />
+                <div class="range-slider-fill" style={ fill_style } />
+                { thumb(Thumb::Low, low) }
+                { thumb(Thumb::High, high) }
+            </div>
+            { output }
+            { help }
+            { feedback }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap_rounds_to_step() {
+        assert_eq!(snap(12.0, 0.0, 100.0, Some(5.0)), 10.0);
+        assert_eq!(snap(13.0, 0.0, 100.0, Some(5.0)), 15.0);
+        assert_eq!(snap(-3.0, 0.0, 100.0, Some(5.0)), 0.0);
+        assert_eq!(snap(99.0, 0.0, 98.0, Some(5.0)), 95.0);
+        assert_eq!(snap(0.31, 0.0, 1.0, Some(0.1)), 0.3);
+        assert_eq!(snap(0.31, 0.0, 1.0, None), 0.31);
+        assert_eq!(normalize((80.0, 20.0), 0.0, 100.0, None), (20.0, 80.0));
+    }
+
+    #[test]
+    fn thumbs_do_not_cross() {
+        assert_eq!(move_thumb((20.0, 80.0), Thumb::Low, 30.0), (30.0, 80.0));
+        assert_eq!(move_thumb((20.0, 80.0), Thumb::Low, 90.0), (80.0, 80.0));
+        assert_eq!(move_thumb((20.0, 80.0), Thumb::High, 10.0), (20.0, 20.0));
+        assert_eq!(percent(25.0, 0.0, 200.0), 12.5);
+        assert_eq!(percent(5.0, 5.0, 5.0), 0.0);
+    }
+
+    #[test]
+    fn form_value_round_trips() {
+        assert_eq!(form_value((10.0, 25.5)), "10,25.5");
+        assert_eq!(parse_range_value(&form_value((-1.5, 3.0))), Some((-1.5, 3.0)));
+        assert_eq!(parse_range_value("a,b"), None);
+    }
+}
@@ -0,0 +1,436 @@
+use yew::prelude::*;
+
+use super::*;
+use crate::component::Button;
+use crate::util::Color;
+
+/// # Value of a field of a [BootstrapForm]
+///
+/// Type of a field which can be edited by a [FormControl], converted from and to
+/// the value of the control. It is implemented for:
+///
+/// - `String` and [AttrValue], edited with [FormControlType::Text]
+/// - `bool`, edited with [FormControlType::Checkbox]
+/// - integers, edited with [FormControlType::Number]
+/// - `f32` and `f64`, edited with [FormControlType::Decimal]
+/// - `Option<T>`, where an empty value is `None`
+/// - enums with unit variants deriving it with `#[derive(FormValue)]`, edited
+///   with [FormControlType::Select]. The label of a variant is its name, or the one
+///   set with `#[form(label = "...")]`, the only attribute of variants.
+///
+/// ```compile_fail
+/// use yew_bootstrap::component::form::*;
+///
+/// #[derive(FormValue)]
+/// enum Plan {
+///     #[form(placeholder = "Free plan")]
+///     Free,
+/// }
+/// ```
+pub trait FormValue: Sized {
+    /// Type of control editing the value
+    fn control_type() -> FormControlType;
+
+    /// Value of the control
+    fn to_form_value(&self) -> AttrValue;
+
+    /// Parse the value of the control, or return the message displayed when it is
+    /// invalid
+    fn from_form_value(value: &str) -> Result<Self, AttrValue>;
+
+    /// Options of a [FormControlType::Select] control, `value` being selected
+    fn select_options(_value: Option<&Self>) -> Html {
+        Html::default()
+    }
+}
+
+impl FormValue for String {
+    fn control_type() -> FormControlType {
+        FormControlType::Text
+    }
+
+    fn to_form_value(&self) -> AttrValue {
+        AttrValue::from(self.clone())
+    }
+
+    fn from_form_value(value: &str) -> Result<Self, AttrValue> {
+        Ok(value.to_string())
+    }
+}
+
+impl FormValue for AttrValue {
+    fn control_type() -> FormControlType {
+        FormControlType::Text
+    }
+
+    fn to_form_value(&self) -> AttrValue {
+        self.clone()
+    }
+
+    fn from_form_value(value: &str) -> Result<Self, AttrValue> {
+        Ok(AttrValue::from(value.to_string()))
+    }
+}
+
+impl FormValue for bool {
+    fn control_type() -> FormControlType {
+        FormControlType::Checkbox
+    }
+
+    fn to_form_value(&self) -> AttrValue {
+        AttrValue::from(if *self { "on" } else { "" })
+    }
+
+    fn from_form_value(value: &str) -> Result<Self, AttrValue> {
+        Ok(!value.is_empty())
+    }
+}
+
+macro_rules! impl_form_value_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl FormValue for $ty {
+                fn control_type() -> FormControlType {
+                    FormControlType::Number { min: None, max: None }
+                }
+
+                fn to_form_value(&self) -> AttrValue {
+                    AttrValue::from(self.to_string())
+                }
+
+                fn from_form_value(value: &str) -> Result<Self, AttrValue> {
+                    value.trim().parse().map_err(|_| AttrValue::from(format!(
+                        "Enter a whole number between {} and {}", <$ty>::MIN, <$ty>::MAX
+                    )))
+                }
+            }
+        )*
+    };
+}
+
+impl_form_value_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! impl_form_value_float {
+    ($($ty:ty),*) => {
+        $(
+            impl FormValue for $ty {
+                fn control_type() -> FormControlType {
+                    FormControlType::Decimal { min: None, max: None, step: None }
+                }
+
+                fn to_form_value(&self) -> AttrValue {
+                    AttrValue::from(self.to_string())
+                }
+
+                fn from_form_value(value: &str) -> Result<Self, AttrValue> {
+                    value.trim().parse().ok().filter(|value: &$ty| value.is_finite())
+                        .ok_or_else(|| AttrValue::from("Enter a number"))
+                }
+            }
+        )*
+    };
+}
+
+impl_form_value_float!(f32, f64);
+
+impl<T: FormValue> FormValue for Option<T> {
+    fn control_type() -> FormControlType {
+        T::control_type()
+    }
+
+    fn to_form_value(&self) -> AttrValue {
+        self.as_ref().map(T::to_form_value).unwrap_or_default()
+    }
+
+    fn from_form_value(value: &str) -> Result<Self, AttrValue> {
+        match value.trim() {
+            "" => Ok(None),
+            _ => T::from_form_value(value).map(Some),
+        }
+    }
+
+    fn select_options(value: Option<&Self>) -> Html {
+        let value = value.and_then(Option::as_ref);
+        html! {
+            <>
+                <SelectOption key="" label="" value="" selected={value.is_none()} />
+                { T::select_options(value) }
+            </>
+        }
+    }
+}
+
+/// Attributes of the [FormControl] of a field of a [BootstrapForm], set by the
+/// `#[form(...)]` attributes of the derive macro
+pub struct FieldAttrs {
+    /// Name of the field
+    pub name: AttrValue,
+    /// Id of the control
+    pub id: AttrValue,
+    /// Label of the control
+    pub label: AttrValue,
+    /// Optional help text
+    pub help: Option<AttrValue>,
+    /// Optional placeholder
+    pub placeholder: Option<AttrValue>,
+    /// Autocomplete type
+    pub autocomplete: FormAutocompleteType,
+    /// Type of control, instead of the one of the [FormValue]
+    pub ctype: Option<FormControlType>,
+    /// Rules checked on the value, in addition to its parsing
+    pub validators: Vec<Validator>,
+}
+
+/// [FormControl] editing a field of type `T` of a [BootstrapForm], with its
+/// current `value` if any. Used by the derive macro.
+pub fn field_control<T: FormValue + 'static>(attrs: FieldAttrs, value: Option<&T>) -> Html {
+    let ctype = attrs.ctype.unwrap_or_else(T::control_type);
+    let children = match ctype {
+        FormControlType::Select => T::select_options(value),
+        _ => Html::default(),
+    };
+    let current = value.map(T::to_form_value).unwrap_or_default();
+    let (value, checked) = match ctype {
+        FormControlType::Checkbox => (AttrValue::default(), !current.is_empty()),
+        _ => (current, false),
+    };
+
+    html! {
+        <FieldControl<T>
+            id={ attrs.id }
+            name={ attrs.name }
+            { ctype }
+            label={ attrs.label }
+            help={ attrs.help }
+            placeholder={ attrs.placeholder }
+            autocomplete={ attrs.autocomplete }
+            { value }
+            { checked }
+            validators={ attrs.validators }
+        >
+            { children }
+        </FieldControl<T>>
+    }
+}
+
+/// # Properties of [FieldControl]
+/// The [FieldAttrs] of the field, with the value of its control
+#[derive(Properties, PartialEq)]
+struct FieldControlProps {
+    id: AttrValue,
+    name: AttrValue,
+    ctype: FormControlType,
+    label: AttrValue,
+    help: Option<AttrValue>,
+    placeholder: Option<AttrValue>,
+    autocomplete: FormAutocompleteType,
+    value: AttrValue,
+    checked: bool,
+    validators: Vec<Validator>,
+    children: Html,
+}
+
+/// [FormControl] of a field of type `T`, whose value must also be parsed.
+///
+/// The validators of a field are set by its attributes, so they are only built
+/// once: a [Validator::Custom] rule is a new one each time it is created, which
+/// would register the validators again in the [Form] on each render.
+#[function_component]
+fn FieldControl<T: FormValue + 'static>(props: &FieldControlProps) -> Html {
+    let validators = use_memo(props.name.clone(), |_| {
+        let mut validators = props.validators.clone();
+        validators.push(Validator::custom(|value| T::from_form_value(value).map(|_| ())));
+        validators
+    });
+
+    html! {
+        <FormControl
+            id={ props.id.clone() }
+            name={ props.name.clone() }
+            class="mb-3"
+            ctype={ props.ctype.clone() }
+            label={ props.label.clone() }
+            help={ props.help.clone() }
+            placeholder={ props.placeholder.clone() }
+            autocomplete={ props.autocomplete.clone() }
+            value={ props.value.clone() }
+            checked={ props.checked }
+            validators={ (*validators).clone() }
+        >
+            { props.children.clone() }
+        </FormControl>
+    }
+}
+
+/// Parse the value of the field `name` of type `T` of a [BootstrapForm]. Used by
+/// the derive macro.
+pub fn field_value<T: FormValue>(values: &FormValues, name: &str) -> Result<T, AttrValue> {
+    T::from_form_value(values.get(name).map(AttrValue::as_str).unwrap_or_default())
+}
+
+/// # Struct edited by a form
+///
+/// Struct whose fields are edited by [FormControl] components in a [Form], and
+/// which is parsed back from the values of the form. Use [StructForm] to display
+/// the form.
+///
+/// It is implemented with `#[derive(BootstrapForm)]`. The type of control of each field is chosen from its type, see
+/// [FormValue]. Fields accept these attributes:
+///
+/// - `#[form(label = "...")]`: label, the name of the field by default
+/// - `#[form(help = "...")]`, `#[form(placeholder = "...")]`
+/// - `#[form(autocomplete = FormAutocompleteType::Email)]`
+/// - `#[form(date)]`, `#[form(password)]`, `#[form(textarea)]` or
+///   `#[form(ctype = FormControlType::...)]` to change the type of control, for
+///   example for a date stored in a `String`
+/// - `#[form(required)]` or `#[form(required = "message")]`, `#[form(min_length = 3)]`,
+///   `#[form(max_length = 16)]`, `#[form(pattern = "[a-z]+")]` or
+///   `#[form(validator = Validator::...)]` to add [Validator] rules
+///
+/// Enums derive [FormValue][derive@FormValue] instead, to be edited as a field:
+///
+/// ```compile_fail
+/// use yew_bootstrap::component::form::*;
+///
+/// #[derive(BootstrapForm)]
+/// enum Plan {
+///     Free,
+/// }
+/// ```
+pub trait BootstrapForm: Sized {
+    /// Controls of the fields, with the values of `value` if any
+    fn form_controls(value: Option<&Self>) -> Html;
+
+    /// Parse the values of the fields
+    fn from_form_values(values: &FormValues) -> Result<Self, AttrValue>;
+}
+
+/// # Properties of [StructForm]
+#[derive(Properties, Clone, PartialEq)]
+pub struct StructFormProps<T: BootstrapForm + Clone + PartialEq + 'static> {
+    /// Initial values of the fields
+    #[prop_or_default]
+    pub value: Option<T>,
+
+    /// Called with the parsed struct when the form is submitted and all the fields
+    /// are valid
+    pub on_submit: Callback<T>,
+
+    /// Text of the submit button, default "Submit"
+    #[prop_or(AttrValue::from("Submit"))]
+    pub submit_text: AttrValue,
+
+    /// Content displayed between the fields and the submit button
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// When the validation of each field is displayed, default
+    /// [ValidationTrigger::Blur]
+    #[prop_or_default]
+    pub trigger: ValidationTrigger,
+}
+
+/// # Form editing a struct
+/// [Form] with a [FormControl] for each field of a struct implementing
+/// [BootstrapForm], and a submit button.
+///
+/// See [StructFormProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::form::*;
+///
+/// #[derive(FormValue, Clone, PartialEq)]
+/// enum Plan {
+///     Free,
+///     #[form(label = "Professional")]
+///     Pro,
+/// }
+///
+/// #[derive(BootstrapForm, Clone, PartialEq)]
+/// struct Account {
+///     #[form(required, max_length = 16, autocomplete = FormAutocompleteType::Username)]
+///     username: String,
+///     #[form(label = "Date of birth", date, required)]
+///     birth_date: String,
+///     age: Option<u32>,
+///     plan: Plan,
+///     #[form(label = "Subscribe to the newsletter")]
+///     newsletter: bool,
+/// }
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let on_submit = Callback::from(|account: Account| {
+///         let _ = account.plan == Plan::Pro;
+///     });
+///     let value = Account {
+///         username: String::new(),
+///         birth_date: String::new(),
+///         age: None,
+///         plan: Plan::Free,
+///         newsletter: true,
+///     };
+///
+///     html! {
+///         <StructForm<Account> {value} {on_submit} submit_text="Create account" />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn StructForm<T: BootstrapForm + Clone + PartialEq + 'static>(props: &StructFormProps<T>) -> Html {
+    let on_submit = {
+        let on_submit = props.on_submit.clone();
+        Callback::from(move |values: FormValues| {
+            // The values were validated by parsing them, this only fails for an
+            // inconsistent implementation of BootstrapForm
+            if let Ok(value) = T::from_form_values(&values) {
+                on_submit.emit(value);
+            }
+        })
+    };
+
+    html! {
+        <Form class={ props.class.clone() } trigger={ props.trigger } {on_submit}>
+            { T::form_controls(props.value.as_ref()) }
+            { for props.children.iter() }
+            <Button style={ Color::Primary }>{ props.submit_text.clone() }</Button>
+        </Form>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn form_values_parse_fields() {
+        assert_eq!(String::from_form_value(" a "), Ok(String::from(" a ")));
+        assert_eq!(bool::from_form_value("on"), Ok(true));
+        assert_eq!(bool::from_form_value(""), Ok(false));
+        assert_eq!(true.to_form_value(), "on");
+        assert_eq!(u8::from_form_value(" 42"), Ok(42));
+        assert_eq!(u8::from_form_value("256"), Err("Enter a whole number between 0 and 255".into()));
+        assert_eq!(i32::from_form_value("-1"), Ok(-1));
+        assert_eq!(f64::from_form_value("-1.5"), Ok(-1.5));
+        assert_eq!(f64::from_form_value("NaN"), Err("Enter a number".into()));
+        assert_eq!(Option::<u32>::from_form_value(""), Ok(None));
+        assert_eq!(Option::<u32>::from_form_value("7"), Ok(Some(7)));
+        assert!(Option::<u32>::from_form_value("x").is_err());
+        assert_eq!(Some(7u32).to_form_value(), "7");
+        assert_eq!(None::<u32>.to_form_value(), "");
+    }
+
+    #[test]
+    fn field_value_defaults_to_empty() {
+        let values = FormValues::from([("age".into(), "30".into())]);
+        assert_eq!(field_value::<u32>(&values, "age"), Ok(30));
+        assert_eq!(field_value::<Option<u32>>(&values, "height"), Ok(None));
+        assert_eq!(field_value::<bool>(&values, "newsletter"), Ok(false));
+    }
+}
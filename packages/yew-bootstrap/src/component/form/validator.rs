@@ -0,0 +1,165 @@
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+use yew::prelude::*;
+
+#[cfg(doc)]
+use super::{Form, FormControl};
+
+#[wasm_bindgen]
+extern "C" {
+    /// JavaScript regular expression
+    type RegExp;
+
+    /// `RegExp(pattern, flags)`, returning the exception if the pattern is invalid
+    #[wasm_bindgen(catch, js_name = RegExp)]
+    fn try_regexp(pattern: &str, flags: &str) -> Result<RegExp, JsValue>;
+
+    /// Does the regular expression match `text`?
+    #[wasm_bindgen(method)]
+    fn test(this: &RegExp, text: &str) -> bool;
+}
+
+/// Type of the function inside [ValidatorFn] structure.
+///
+/// It receives the value of the field, and returns the error message if the value
+/// is invalid.
+type ValidatorFnInner = dyn Fn(&str) -> Result<(), AttrValue>;
+
+/// # Custom validation function
+///
+/// Structure containing a function validating the value of a field, used by
+/// [Validator::Custom]. It can be created from any closure:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::form::ValidatorFn;
+///
+/// let even = ValidatorFn::from(|value: &str| match value.parse::<i32>() {
+///     Ok(number) if number % 2 == 0 => Ok(()),
+///     _ => Err(AttrValue::from("Enter an even number")),
+/// });
+/// ```
+pub struct ValidatorFn(
+    /// Internal function, inside [Rc] for cheap Clone implementation.
+    pub Rc<ValidatorFnInner>
+);
+
+impl Clone for ValidatorFn {
+    fn clone(&self) -> Self {
+        ValidatorFn(self.0.clone())
+    }
+}
+
+impl PartialEq for ValidatorFn {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<F> From<F> for ValidatorFn
+where
+    F: Fn(&str) -> Result<(), AttrValue> + 'static,
+{
+    fn from(f: F) -> Self {
+        ValidatorFn(Rc::new(f))
+    }
+}
+
+/// # Validation rule of a [FormControl]
+///
+/// Rule checked on the value of a [FormControl] inside a [Form], each with the
+/// message displayed when the value is invalid. Apart from [Validator::Required],
+/// rules accept empty values.
+///
+/// For a checkbox, the value is empty when it is not checked, so that
+/// [Validator::Required] requires it to be checked.
+#[derive(Clone, PartialEq)]
+pub enum Validator {
+    /// The value is not empty or blank
+    Required(AttrValue),
+    /// The value has at least this number of characters
+    MinLength(usize, AttrValue),
+    /// The value has at most this number of characters
+    MaxLength(usize, AttrValue),
+    /// The whole value matches this JavaScript regular expression, like the
+    /// `pattern` attribute of inputs. An invalid regular expression fails the rule.
+    Pattern(AttrValue, AttrValue),
+    /// The value is accepted by this function, which returns the message otherwise
+    Custom(ValidatorFn),
+}
+
+impl Validator {
+    /// [Validator::Custom] rule from a closure, see [ValidatorFn]
+    pub fn custom<F>(f: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), AttrValue> + 'static,
+    {
+        Validator::Custom(ValidatorFn::from(f))
+    }
+
+    /// Check `value`, returning the message of the rule if it is invalid
+    pub fn validate(&self, value: &str) -> Result<(), AttrValue> {
+        let (valid, message) = match self {
+            Validator::Required(message) => (!value.trim().is_empty(), message),
+            Validator::MinLength(min, message) => (value.is_empty() || value.chars().count() >= *min, message),
+            Validator::MaxLength(max, message) => (value.chars().count() <= *max, message),
+            Validator::Pattern(pattern, message) => (
+                value.is_empty() || try_regexp(&format!("^(?:{pattern})$"), "u").is_ok_and(|regexp| regexp.test(value)),
+                message,
+            ),
+            Validator::Custom(f) => return f.0(value),
+        };
+
+        if valid { Ok(()) } else { Err(message.clone()) }
+    }
+}
+
+/// Check `value` with each of the `validators`, returning the message of the first
+/// one failing
+pub fn validate(value: &str, validators: &[Validator]) -> Result<(), AttrValue> {
+    validators.iter().try_for_each(|validator| validator.validate(value))
+}
+
+// Validator::Pattern relies on the JavaScript RegExp, so it cannot be unit-tested
+// natively and is not covered here
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validators_check_value() {
+        let required = Validator::Required("Required".into());
+        assert_eq!(required.validate(""), Err("Required".into()));
+        assert_eq!(required.validate("  "), Err("Required".into()));
+        assert_eq!(required.validate("a"), Ok(()));
+
+        let min = Validator::MinLength(3, "Too short".into());
+        assert_eq!(min.validate(""), Ok(()));
+        assert_eq!(min.validate("ab"), Err("Too short".into()));
+        assert_eq!(min.validate("été"), Ok(()));
+
+        let max = Validator::MaxLength(3, "Too long".into());
+        assert_eq!(max.validate("été"), Ok(()));
+        assert_eq!(max.validate("abcd"), Err("Too long".into()));
+
+        let custom = Validator::custom(|value| {
+            if value.contains('@') { Ok(()) } else { Err("Missing @".into()) }
+        });
+        assert_eq!(custom.validate("a@b"), Ok(()));
+        assert_eq!(custom.validate("ab"), Err("Missing @".into()));
+    }
+
+    #[test]
+    fn validate_returns_first_error() {
+        let validators = [
+            Validator::Required("Required".into()),
+            Validator::MinLength(3, "Too short".into()),
+            Validator::MaxLength(1, "Too long".into()),
+        ];
+        assert_eq!(validate("", &validators), Err("Required".into()));
+        assert_eq!(validate("ab", &validators), Err("Too short".into()));
+        assert_eq!(validate("abc", &validators), Err("Too long".into()));
+        assert_eq!(validate("abc", &[]), Ok(()));
+    }
+}
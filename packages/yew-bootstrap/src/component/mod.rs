@@ -1,48 +1,72 @@
 mod accordion;
 mod alert;
 mod badge;
+mod breadcrumb;
 mod button;
 mod button_group;
 pub mod card;
+mod carousel;
+mod collapse;
 mod column;
 mod container;
 mod display;
+mod dropdown;
 pub mod form;
 mod lead;
 mod line;
 mod link;
 mod list_group;
 mod modal;
+mod nav;
 mod navbar;
+mod offcanvas;
+mod pagination;
+mod placeholder;
+mod popover;
 mod row;
 mod spinner;
+mod table;
+mod tabs;
 mod progress;
 
 #[cfg(feature = "searchable_select")]
 mod searchable_select;
 
+mod toast;
 mod tooltip;
 
 
 pub use self::accordion::*;
 pub use self::alert::*;
 pub use self::badge::*;
+pub use self::breadcrumb::*;
 pub use self::button::*;
 pub use self::button_group::*;
+pub use self::carousel::*;
+pub use self::collapse::*;
 pub use self::column::*;
 pub use self::container::*;
 pub use self::display::*;
+pub use self::dropdown::*;
 pub use self::lead::*;
 pub use self::line::*;
 pub use self::link::*;
 pub use self::list_group::*;
 pub use self::modal::*;
+pub use self::nav::*;
 pub use self::navbar::*;
+pub use self::offcanvas::*;
+pub use self::pagination::*;
+pub use self::placeholder::*;
+pub use self::popover::*;
 pub use self::row::*;
 pub use self::spinner::*;
+pub use self::table::*;
+pub use self::tabs::*;
 pub use self::progress::*;
 
 #[cfg(feature = "searchable_select")]
 pub use self::searchable_select::*;
 
+pub use self::toast::*;
 pub use self::tooltip::*;
@@ -1,8 +1,12 @@
-use web_sys::EventTarget;
+use gloo_timers::callback::Timeout;
+use wasm_bindgen::JsCast;
+use web_sys::{EventTarget, HtmlElement};
 use yew::prelude::*;
 use gloo_events::EventListenerOptions;
 use gloo_utils::body;
 
+use crate::util::{use_body_scroll_lock, use_transition, MODAL_DURATION};
+
 /// Represents the optional size of a Modal dialog, described [here](https://getbootstrap.com/docs/5.1/components/modal/#optional-sizes)
 #[derive(Default, Clone, PartialEq, Eq)]
 pub enum ModalSize {
@@ -13,6 +17,29 @@ pub enum ModalSize {
     Small,
 }
 
+/// Backdrop of a [Modal] dialog controlled by its `show` property
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ModalBackdrop {
+    /// Backdrop closing the modal when clicked
+    #[default]
+    Normal,
+    /// Backdrop which doesn't close the modal when clicked
+    Static,
+    /// No backdrop
+    None,
+}
+
+/// # Context of a [Modal] dialog
+///
+/// Provided by a [Modal] when it is controlled by its `show` property, so that
+/// children like [ModalHeader] or a [crate::component::Button] with `modal_dismiss`
+/// can request the modal to be closed.
+#[derive(Clone, PartialEq)]
+pub struct ModalContext {
+    /// Request the modal to be closed, calls `on_close` on the [Modal]
+    pub close: Callback<()>,
+}
+
 /// # Modal dialog
 /// Modal dialog, parent of [ModalHeader], [ModalBody] and [ModalFooter].
 /// 
@@ -38,13 +65,196 @@ pub enum ModalSize {
 ///     }
 /// }
 /// ```
-pub struct Modal {
-    #[allow(dead_code)]
-    on_hide: OnHide,
+///
+/// ## Controlled by Yew
+///
+/// By default the dialog is opened and closed by Bootstrap's JavaScript library, using the
+/// `modal_target` and `modal_dismiss` properties of [crate::component::Button]. When `show`
+/// is set, the dialog is instead entirely managed by this component, including the backdrop,
+/// fade transition, lock of the body scroll and closing with <kbd>Escape</kbd>, and
+/// [crate::util::include_cdn_js] is no longer needed.
+///
+/// `on_close` is called each time the user requests the dialog to be closed, and the
+/// application is responsible for setting `show` to false.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Modal, ModalHeader, ModalBody, ModalFooter, Button};
+/// use yew_bootstrap::util::Color;
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let show = use_state(|| false);
+///     let open = { let show = show.clone(); Callback::from(move |_| show.set(true)) };
+///     let close = { let show = show.clone(); Callback::from(move |_| show.set(false)) };
+///
+///     html!{
+///         <>
+///             <Button style={ Color::Primary } onclick={ open }>{ "Open" }</Button>
+///             <Modal show={ *show } on_close={ close }>
+///                 <ModalHeader title="Modal title" />
+///                 <ModalBody>
+///                     <p>{"Modal body text goes here."}</p>
+///                 </ModalBody>
+///                 <ModalFooter>
+///                     <Button style={ Color::Secondary } modal_dismiss={ true }>{ "Close" }</Button>
+///                 </ModalFooter>
+///             </Modal>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Modal(props: &ModalProps) -> Html {
+    let modal_ref = use_node_ref();
+
+    // Only used when the modal is controlled by Bootstrap's JavaScript
+    let _on_hide = use_memo(props.on_hide.clone(), |on_hide| {
+        OnHide::new(&body(), on_hide.clone())
+    });
+
+    let controlled = props.show.is_some();
+    let duration = if props.fade { MODAL_DURATION } else { 0 };
+    let stage = use_transition(props.show.unwrap_or_default(), duration, modal_ref.clone(), true);
+    use_body_scroll_lock(controlled && stage.is_visible());
+
+    // Static backdrop is animated when trying to close the modal
+    let bounce = use_state_eq(|| false);
+    {
+        let bounce = bounce.clone();
+        use_effect_with(*bounce, move |bouncing| {
+            let timeout = bouncing.then(|| Timeout::new(MODAL_DURATION, move || bounce.set(false)));
+            move || drop(timeout)
+        });
+    }
+    let on_static = {
+        let bounce = bounce.clone();
+        Callback::from(move |_| bounce.set(true))
+    };
+
+    // Move focus to the dialog when opened, to receive keyboard events
+    {
+        let modal_ref = modal_ref.clone();
+        use_effect_with(stage.is_visible(), move |visible| {
+            if *visible && controlled {
+                if let Some(element) = modal_ref.cast::<HtmlElement>() {
+                    let _ = element.focus();
+                }
+            }
+        });
+    }
+
+    let mut dialog_classes = Classes::new();
+    dialog_classes.push("modal-dialog");
+
+    match props.size {
+        ModalSize::ExtraLarge => dialog_classes.push("modal-xl"),
+        ModalSize::Large => dialog_classes.push("modal-lg"),
+        ModalSize::Small => dialog_classes.push("modal-sm"),
+        _ => (),
+    }
+
+    if !controlled {
+        return html! {
+            <div class="modal" tabindex="-1" id={props.id.clone()}>
+                <div class={dialog_classes}>
+                    <div class="modal-content">
+                        { for props.children.iter() }
+                    </div>
+                </div>
+            </div>
+        };
+    }
+
+    let onkeydown = {
+        let on_close = props.on_close.clone();
+        let on_static = on_static.clone();
+        let keyboard = props.keyboard;
+        let backdrop = props.backdrop;
+        Callback::from(move |event: KeyboardEvent| {
+            if event.key() != "Escape" {
+                return;
+            }
+            event.prevent_default();
+            if keyboard {
+                on_close.emit(());
+            } else if backdrop == ModalBackdrop::Static {
+                on_static.emit(());
+            }
+        })
+    };
+
+    // The modal element covers the whole page, clicks outside the dialog are on the backdrop
+    let onclick = {
+        let on_close = props.on_close.clone();
+        let backdrop = props.backdrop;
+        let modal_ref = modal_ref.clone();
+        Callback::from(move |event: MouseEvent| {
+            let target = event.target().and_then(|t| t.dyn_into::<HtmlElement>().ok());
+            if target.is_none() || target != modal_ref.cast::<HtmlElement>() {
+                return;
+            }
+            match backdrop {
+                ModalBackdrop::Normal => on_close.emit(()),
+                ModalBackdrop::Static => on_static.emit(()),
+                ModalBackdrop::None => (),
+            }
+        })
+    };
+
+    let mut classes = classes!("modal");
+    if props.fade {
+        classes.push("fade");
+    }
+    if stage.is_shown() {
+        classes.push("show");
+    }
+    if *bounce {
+        classes.push("modal-static");
+    }
+
+    let mut backdrop_classes = classes!("modal-backdrop");
+    if props.fade {
+        backdrop_classes.push("fade");
+    }
+    if stage.is_shown() {
+        backdrop_classes.push("show");
+    }
+
+    let context = ModalContext { close: props.on_close.clone() };
+
+    html! {
+        <ContextProvider<ModalContext> {context}>
+            <div
+                ref={modal_ref}
+                class={classes}
+                tabindex="-1"
+                id={props.id.clone()}
+                style={stage.is_visible().then_some("display: block;")}
+                role={stage.is_visible().then_some("dialog")}
+                aria-modal={stage.is_visible().then_some("true")}
+                aria-hidden={(!stage.is_visible()).then_some("true")}
+                {onclick}
+                {onkeydown}
+            >
+                <div class={dialog_classes}>
+                    <div class="modal-content">
+                        { for props.children.iter() }
+                    </div>
+                </div>
+            </div>
+            if stage.is_visible() && props.backdrop != ModalBackdrop::None {
+                <div class={backdrop_classes}></div>
+            }
+        </ContextProvider<ModalContext>>
+    }
 }
 
 /// # Header for a [Modal] dialog
 /// See [ModalHeaderProps] for a listing of properties
+///
+/// The close button requests the [Modal] to be closed, either through its `on_close`
+/// callback when controlled by Yew, or with Bootstrap's JavaScript.
 pub struct ModalHeader { }
 
 /// # Body for a [Modal] dialog
@@ -103,11 +313,21 @@ impl Component for ModalHeader {
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
+        let context = ctx.link().context::<ModalContext>(Callback::noop()).map(|(context, _)| context);
 
         html! {
             <div class="modal-header">
                 <h5 class="modal-title" id={format!("#{}", props.id.clone())}>{props.title.clone()}</h5>
-                <button type="button" class="btn-close" data-bs-dismiss="modal" aria-label="Close"></button>
+                if let Some(context) = context {
+                    <button
+                        type="button"
+                        class="btn-close"
+                        aria-label="Close"
+                        onclick={context.close.reform(|_| ())}
+                    ></button>
+                } else {
+                    <button type="button" class="btn-close" data-bs-dismiss="modal" aria-label="Close"></button>
+                }
             </div>
         }
     }
@@ -179,41 +399,22 @@ pub struct ModalProps {
     /// Function to be called on the 'hide.bs.modal' event, takes no parameters
     #[prop_or_default]
     pub on_hide: Option<Callback<Event>>,
+    /// If set, the modal is shown or hidden by Yew instead of Bootstrap's JavaScript.
+    /// See [Modal] for details.
+    #[prop_or_default]
+    pub show: Option<bool>,
+    /// Called when the user requests to close a modal controlled by `show`: close button
+    /// of the [ModalHeader], button with `modal_dismiss`, <kbd>Escape</kbd> key or click
+    /// on the backdrop.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+    /// Backdrop of a modal controlled by `show`, see [ModalBackdrop]
+    #[prop_or_default]
+    pub backdrop: ModalBackdrop,
+    /// Close a modal controlled by `show` when <kbd>Escape</kbd> is pressed, default true
+    #[prop_or(true)]
+    pub keyboard: bool,
+    /// Use a fade transition for a modal controlled by `show`, default true
+    #[prop_or(true)]
+    pub fade: bool,
 }
-
-impl Component for Modal {
-    type Message = ();
-    type Properties = ModalProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        let body = body();
-        Self { on_hide: OnHide::new(
-            &body,
-            _ctx.props().on_hide.clone(),
-        )}
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-
-        let mut dialog_classes = Classes::new();
-        dialog_classes.push("modal-dialog");
-
-        match props.size {
-            ModalSize::ExtraLarge => dialog_classes.push("modal-xl"),
-            ModalSize::Large => dialog_classes.push("modal-lg"),
-            ModalSize::Small => dialog_classes.push("modal-sm"),
-            _ => (),
-        }
-
-        html! {
-            <div class="modal" tabindex="-1" id={props.id.clone()}>
-                <div class={dialog_classes}>
-                    <div class="modal-content">
-                        { for props.children.iter() }
-                    </div>
-                </div>
-            </div>
-        }
-    }
-}
\ No newline at end of file
@@ -0,0 +1,100 @@
+use yew::prelude::*;
+
+/// Style of a [Nav] or [crate::component::Tabs]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NavVariant {
+    /// Tabbed interface
+    Tabs,
+    /// Links displayed as pills
+    Pills,
+    /// Active link is underlined, requires Bootstrap 5.3
+    Underline,
+}
+
+/// Classes of the `nav` element
+pub(crate) fn nav_classes(variant: Option<NavVariant>, fill: bool, justified: bool, vertical: bool) -> Classes {
+    let mut classes = classes!("nav");
+    match variant {
+        Some(NavVariant::Tabs) => classes.push("nav-tabs"),
+        Some(NavVariant::Pills) => classes.push("nav-pills"),
+        Some(NavVariant::Underline) => classes.push("nav-underline"),
+        None => (),
+    }
+    if fill {
+        classes.push("nav-fill");
+    }
+    if justified {
+        classes.push("nav-justified");
+    }
+    if vertical {
+        classes.push("flex-column");
+    }
+    classes
+}
+
+/// # Properties of [Nav]
+#[derive(Properties, Clone, PartialEq)]
+pub struct NavProps {
+    /// Items of the navigation, typically [crate::component::NavItem]
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Optional HTML id of the navigation
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+
+    /// Style of the navigation, plain links if unset
+    #[prop_or_default]
+    pub variant: Option<NavVariant>,
+
+    /// Items take all the available width, proportionally to their content
+    #[prop_or_default]
+    pub fill: bool,
+
+    /// Items take all the available width, with the same width
+    #[prop_or_default]
+    pub justified: bool,
+
+    /// Items are stacked vertically
+    #[prop_or_default]
+    pub vertical: bool,
+}
+
+/// # Nav component
+/// Standalone navigation, outside of a [crate::component::NavBar]. Children are
+/// typically [crate::component::NavItem] components.
+///
+/// To switch between panes of content, see [crate::component::Tabs].
+///
+/// See [NavProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Nav, NavItem, NavVariant};
+///
+/// fn test() -> Html {
+///     html!{
+///         <Nav variant={NavVariant::Pills} fill={true}>
+///             <NavItem text="Active" url="#" active={true} />
+///             <NavItem text="Link" url="#" />
+///             <NavItem text="Disabled" disabled={true} />
+///         </Nav>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Nav(props: &NavProps) -> Html {
+    let mut classes = nav_classes(props.variant, props.fill, props.justified, props.vertical);
+    classes.push(props.class.clone());
+
+    html! {
+        <ul class={classes} id={props.id.clone()}>
+            { for props.children.iter() }
+        </ul>
+    }
+}
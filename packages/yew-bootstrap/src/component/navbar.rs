@@ -1,8 +1,22 @@
+use gloo_events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::Node;
 use yew::prelude::*;
-use super::Container;
+use super::{Collapse, Container, ContainerSize};
 use crate::util::Dimension;
 use crate::icons::BI;
 
+/// # Context of a [NavBar]
+///
+/// Provided by a [NavBar] to its children, so that [NavItem], [NavDropdown] or
+/// custom components can collapse the menu on small screens once a link is
+/// clicked.
+#[derive(Clone, PartialEq)]
+pub struct NavBarContext {
+    /// Collapse the menu, if it is expanded
+    pub collapse: Callback<()>,
+}
+
 /// # A singular dropdown item, child of [NavDropdown]
 /// Used as a child of [NavDropdown] to create a dropdown menu.
 ///
@@ -59,8 +73,26 @@ impl Component for NavDropdownItem {
 }
 
 /// A dropdown menu, child of [NavBar]. See [NavDropdownProps] for a listing of properties.
-#[derive(Clone, PartialEq, Eq)]
-pub struct NavDropdown { }
+///
+/// The menu is opened by clicking on its link, and closed by clicking anywhere
+/// else, on one of its items, or with the <kbd>Escape</kbd> key. Clicking an item
+/// also collapses the menu of the [NavBar] on small screens.
+pub struct NavDropdown {
+    open: bool,
+    toggle_ref: NodeRef,
+    menu_ref: NodeRef,
+    on_document_click: Option<EventListener>,
+}
+
+/// Message of [NavDropdown]
+pub enum NavDropdownMsg {
+    /// Open or close the menu
+    Toggle,
+    /// Close the menu
+    Close,
+    /// An item was clicked: close the menu and collapse the [NavBar]
+    Select,
+}
 
 /// Properties for [NavDropdown]
 #[derive(Properties, Clone, PartialEq)]
@@ -70,7 +102,7 @@ pub struct NavDropdownProps {
     /// the id of the link with the dropdown-toggle class, referenced by aria-labelledby
     #[prop_or_default]
     pub id: AttrValue,
-    /// If true, menu is expanded (ie visible)
+    /// If true, menu is initially expanded (ie visible)
     #[prop_or_default]
     pub expanded: bool,
     /// the text of the link with the dropdown-toggle class
@@ -85,22 +117,71 @@ pub struct NavDropdownProps {
 }
 
 impl Component for NavDropdown {
-    type Message = ();
+    type Message = NavDropdownMsg;
     type Properties = NavDropdownProps;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self { }
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            open: ctx.props().expanded,
+            toggle_ref: NodeRef::default(),
+            menu_ref: NodeRef::default(),
+            on_document_click: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let open = match msg {
+            NavDropdownMsg::Toggle => !self.open,
+            NavDropdownMsg::Close => false,
+            NavDropdownMsg::Select => {
+                if let Some((navbar, _)) = ctx.link().context::<NavBarContext>(Callback::noop()) {
+                    navbar.collapse.emit(());
+                }
+                false
+            }
+        };
+        if open == self.open {
+            return false;
+        }
+
+        // Close on clicks outside of the link, and on the items
+        self.on_document_click = open.then(|| {
+            let link = ctx.link().clone();
+            let toggle_ref = self.toggle_ref.clone();
+            let menu_ref = self.menu_ref.clone();
+            EventListener::new(&gloo_utils::document(), "click", move |event| {
+                let Some(target) = event.target().and_then(|target| target.dyn_into::<Node>().ok()) else {
+                    return;
+                };
+                if toggle_ref.get().is_some_and(|toggle| toggle.contains(Some(&target))) {
+                    return;
+                }
+                link.send_message(match menu_ref.get().is_some_and(|menu| menu.contains(Some(&target))) {
+                    true => NavDropdownMsg::Select,
+                    false => NavDropdownMsg::Close,
+                });
+            })
+        });
+        self.open = open;
+        true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
 
-        let expanded = String::from(match props.expanded {
+        let onclick = ctx.link().callback(|event: MouseEvent| {
+            event.prevent_default();
+            NavDropdownMsg::Toggle
+        });
+        let onkeydown = ctx.link().batch_callback(|event: KeyboardEvent| {
+            (event.key() == "Escape").then_some(NavDropdownMsg::Close)
+        });
+
+        let expanded = String::from(match self.open {
             true => "true",
             false => "false"
         });
 
-
         let mut dropdown_toggle_classes = Classes::new();
         dropdown_toggle_classes.push(String::from("nav-link"));
         dropdown_toggle_classes.push(String::from("dropdown-toggle"));
@@ -109,15 +190,21 @@ impl Component for NavDropdown {
             dropdown_toggle_classes.push(String::from("active"));
         }
 
+        let mut dropdown_menu_classes = classes!("dropdown-menu");
+        if self.open {
+            dropdown_toggle_classes.push(String::from("show"));
+            dropdown_menu_classes.push("show");
+        }
+
         html! {
-            <li class="nav-item dropdown">
-                <a class={dropdown_toggle_classes} href="#" id={props.id.clone()} role="button" data-bs-toggle="dropdown" aria-expanded={expanded}>
+            <li class="nav-item dropdown" {onkeydown}>
+                <a class={dropdown_toggle_classes} href="#" id={props.id.clone()} role="button" aria-expanded={expanded} ref={self.toggle_ref.clone()} {onclick}>
                     if let Some(icon) = props.icon {
                         {icon}{" "}
                     }
                     {props.text.clone()}
                 </a>
-                <ul class="dropdown-menu" aria-labelledby={props.id.clone()}>
+                <ul class={dropdown_menu_classes} aria-labelledby={props.id.clone()} ref={self.menu_ref.clone()}>
                     { for props.children.iter() }
                 </ul>
             </li>
@@ -128,6 +215,9 @@ impl Component for NavDropdown {
 /// # Item of a [NavBar]
 /// This typically contains text inside a link
 ///
+/// Clicking an enabled link collapses the menu of the parent [NavBar] on small
+/// screens.
+///
 /// Refer to [NavItemProperties] for a listing of properties
 pub struct NavItem { }
 
@@ -173,6 +263,17 @@ impl Component for NavItem {
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
+        let navbar = ctx.link().context::<NavBarContext>(Callback::noop()).map(|(navbar, _)| navbar);
+        let onclick = {
+            let onclick = props.onclick.clone();
+            let disabled = props.disabled;
+            Callback::from(move |event: MouseEvent| {
+                onclick.emit(event);
+                if let Some(navbar) = navbar.as_ref().filter(|_| !disabled) {
+                    navbar.collapse.emit(());
+                }
+            })
+        };
 
         match &props.children.is_empty() {
             true => {
@@ -196,7 +297,7 @@ impl Component for NavItem {
                                     tabindex="-1"
                                     aria-disabled="true"
                                     href={props.url.clone()}
-                                    onclick={props.onclick.clone()}
+                                    onclick={onclick.clone()}
                                 >
                                     if let Some(icon) = props.icon {
                                         {icon}{" "}
@@ -212,7 +313,7 @@ impl Component for NavItem {
                                 <a
                                     class={classes}
                                     href={props.url.clone()}
-                                    onclick={props.onclick.clone()}
+                                    onclick={onclick.clone()}
                                 >
                                     if let Some(icon) = props.icon {
                                         {icon}{" "}
@@ -272,11 +373,17 @@ pub enum BrandType {
 /// We have broken up this component into several sub-components to make it easier to use: [NavItem], [NavDropdown], and [NavDropdownItem].
 /// The brand property is set using the [BrandType] enum.
 ///
+/// This component is implemented with Yew instead of Bootstrap's JavaScript. The
+/// menu is expanded from `expand_at` and larger screens, and toggled by a button
+/// on smaller ones. It is collapsed again when a [NavItem] or an item of a
+/// [NavDropdown] is clicked. The state can be controlled with `open` and
+/// `on_toggle`, and children can collapse it using the [NavBarContext].
+///
 /// See [NavBarProps] for more information on properties supported by this component.
 /// # Example
 /// ```rust
 /// use yew::prelude::*;
-/// use yew_bootstrap::component::{BrandType, NavBar, NavDropdownItem, NavItem};
+/// use yew_bootstrap::component::{BrandType, ContainerSize, NavBar, NavDropdownItem, NavItem};
 ///
 /// fn test() -> Html {
 ///     let brand = BrandType::BrandSimple {
@@ -284,7 +391,7 @@ pub enum BrandType {
 ///         url: Some(AttrValue::from("https://yew.rs"))
 ///     };
 ///     html!{
-///         <NavBar nav_id={"test-nav"} class="navbar-expand-lg navbar-light bg-light" brand={brand}>
+///         <NavBar nav_id={"test-nav"} class="navbar-light bg-light" expand_at={ContainerSize::Large} brand={brand}>
 ///             <NavItem text="Home" url={AttrValue::from("/")} />
 ///             <NavItem text="more">
 ///                 <NavDropdownItem text="dropdown item 1" url={AttrValue::from("/dropdown1")} />
@@ -293,7 +400,37 @@ pub enum BrandType {
 ///     }
 /// }
 /// ```
-pub struct NavBar { }
+///
+/// ## Controlled example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{ContainerSize, NavBar, NavItem};
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let expanded = use_state(|| false);
+///     let on_toggle = { let expanded = expanded.clone(); Callback::from(move |e| expanded.set(e)) };
+///
+///     html!{
+///         <NavBar nav_id="controlled-nav" expand_at={ContainerSize::Medium} open={*expanded} {on_toggle}>
+///             <NavItem text="Home" url="/" />
+///         </NavBar>
+///     }
+/// }
+/// ```
+pub struct NavBar {
+    /// Menu is expanded on small screens, unless controlled by `open`
+    expanded: bool,
+    context: NavBarContext,
+}
+
+/// Message of [NavBar]
+pub enum NavBarMsg {
+    /// Expand or collapse the menu
+    Toggle,
+    /// Collapse the menu, if it is expanded
+    Collapse,
+}
 
 /// Properties for [NavBar]
 #[derive(Properties, Clone, PartialEq)]
@@ -308,10 +445,27 @@ pub struct NavBarProps {
     #[prop_or_default]
     pub nav_id: AttrValue,
 
-    /// Navbar is expanded. Used to notify assitive technologies via aria-expanded
+    /// Screen size from which the menu is always expanded, adding the
+    /// `navbar-expand-*` class. The menu is always collapsible if unset, unless
+    /// this class is included in `class`.
+    #[prop_or_default]
+    pub expand_at: Option<ContainerSize>,
+
+    /// Navbar is initially expanded on small screens. The navbar then manages this
+    /// state itself, unless `open` is set.
     #[prop_or_default]
     pub expanded: bool,
 
+    /// Menu is expanded on small screens. When set, the navbar is controlled: the
+    /// application must update it from `on_toggle`, and `expanded` is ignored.
+    #[prop_or_default]
+    pub open: Option<bool>,
+
+    /// Called when the menu is expanded (`true`) or collapsed (`false`), by the
+    /// toggler button or after clicking a link
+    #[prop_or_default]
+    pub on_toggle: Callback<bool>,
+
     /// Brand type, see [BrandType]
     #[prop_or_default]
     pub brand: Option<BrandType>,
@@ -321,18 +475,43 @@ pub struct NavBarProps {
     pub brand_callback: Callback<MouseEvent>
 }
 
+impl NavBar {
+    /// Menu is expanded on small screens
+    fn is_open(&self, ctx: &Context<Self>) -> bool {
+        ctx.props().open.unwrap_or(self.expanded)
+    }
+}
+
 impl Component for NavBar {
-    type Message = ();
+    type Message = NavBarMsg;
     type Properties = NavBarProps;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {}
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            expanded: ctx.props().expanded,
+            context: NavBarContext { collapse: ctx.link().callback(|_| NavBarMsg::Collapse) },
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let open = match msg {
+            NavBarMsg::Toggle => !self.is_open(ctx),
+            NavBarMsg::Collapse if self.is_open(ctx) => false,
+            NavBarMsg::Collapse => return false,
+        };
+        ctx.props().on_toggle.emit(open);
+        if ctx.props().open.is_some() {
+            return false;
+        }
+        self.expanded = open;
+        true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let props = ctx.props();
+        let open = self.is_open(ctx);
 
-        let expanded = String::from(match &props.expanded {
+        let expanded = String::from(match open {
             true => {
                 "true"
             },
@@ -343,6 +522,11 @@ impl Component for NavBar {
 
         let mut classes = Classes::new();
         classes.push("navbar");
+        match &props.expand_at {
+            Some(ContainerSize::ExtraSmall) => classes.push("navbar-expand"),
+            Some(size) => classes.push(format!("navbar-expand-{}", size.to_string())),
+            None => (),
+        }
         classes.push(props.class.to_string());
 
         let brand = match &props.brand {
@@ -419,20 +603,24 @@ impl Component for NavBar {
             }
         };
 
+        let toggle = ctx.link().callback(|_: MouseEvent| NavBarMsg::Toggle);
+
         html! {
-            <nav class={classes}>
-                <Container fluid=true>
-                    <button class="navbar-toggler" type="button" data-bs-toggle="collapse" data-bs-target={format!("#{}", props.nav_id.clone())} aria-controls={props.nav_id.clone()} aria-expanded={expanded} aria-label="Toggle navigation">
-                        <span class="navbar-toggler-icon"></span>
-                    </button>
-                    {brand}
-                    <div class="collapse navbar-collapse" id={props.nav_id.clone()}>
-                        <ul class="navbar-nav">
-                            { for props.children.clone() }
-                        </ul>
-                    </div>
-                </Container>
-            </nav>
+            <ContextProvider<NavBarContext> context={self.context.clone()}>
+                <nav class={classes}>
+                    <Container fluid=true>
+                        <button class="navbar-toggler" type="button" aria-controls={props.nav_id.clone()} aria-expanded={expanded} aria-label="Toggle navigation" onclick={toggle}>
+                            <span class="navbar-toggler-icon"></span>
+                        </button>
+                        {brand}
+                        <Collapse class="navbar-collapse" id={props.nav_id.clone()} open={open}>
+                            <ul class="navbar-nav">
+                                { for props.children.clone() }
+                            </ul>
+                        </Collapse>
+                    </Container>
+                </nav>
+            </ContextProvider<NavBarContext>>
         }
     }
-}
\ No newline at end of file
+}
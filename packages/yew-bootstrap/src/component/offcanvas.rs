@@ -0,0 +1,348 @@
+use gloo_events::EventListener;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use super::{ContainerSize, ModalBackdrop};
+use crate::util::{use_body_scroll_lock, use_transition, FADE_DURATION, MODAL_DURATION};
+
+/// Side of the viewport from which an [Offcanvas] slides in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OffcanvasPlacement {
+    /// Left of the viewport (for left-to-right languages)
+    #[default]
+    Start,
+    /// Right of the viewport (for left-to-right languages)
+    End,
+    /// Top of the viewport
+    Top,
+    /// Bottom of the viewport
+    Bottom,
+}
+
+impl OffcanvasPlacement {
+    fn class(self) -> &'static str {
+        match self {
+            Self::Start => "offcanvas-start",
+            Self::End => "offcanvas-end",
+            Self::Top => "offcanvas-top",
+            Self::Bottom => "offcanvas-bottom",
+        }
+    }
+}
+
+/// # Context of an [Offcanvas]
+///
+/// Provided by an [Offcanvas] to its children, so that [OffcanvasHeader] or
+/// custom components can request it to be hidden.
+#[derive(Clone, PartialEq)]
+pub struct OffcanvasContext {
+    /// Request the offcanvas to be hidden, calls `on_hide` on the [Offcanvas]
+    pub hide: Callback<()>,
+}
+
+/// # Properties of [Offcanvas]
+#[derive(Properties, Clone, PartialEq)]
+pub struct OffcanvasProps {
+    /// Inner components, typically [OffcanvasHeader] and [OffcanvasBody]
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Optional HTML id of the offcanvas
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+
+    /// Offcanvas is shown
+    #[prop_or_default]
+    pub show: bool,
+
+    /// Called when the user requests to hide the offcanvas: close button of the
+    /// [OffcanvasHeader], <kbd>Escape</kbd> key or click on the backdrop. The
+    /// application is responsible for setting `show` to false.
+    #[prop_or_default]
+    pub on_hide: Callback<()>,
+
+    /// Side of the viewport, default [OffcanvasPlacement::Start]
+    #[prop_or_default]
+    pub placement: OffcanvasPlacement,
+
+    /// Backdrop displayed when the offcanvas is shown, see [ModalBackdrop]
+    #[prop_or_default]
+    pub backdrop: ModalBackdrop,
+
+    /// Allow the page body to scroll while the offcanvas is shown, default false
+    #[prop_or_default]
+    pub scroll: bool,
+
+    /// Hide the offcanvas when <kbd>Escape</kbd> is pressed, default true
+    #[prop_or(true)]
+    pub keyboard: bool,
+
+    /// If set, the content is only an offcanvas below this size, and is displayed
+    /// in the page from this size (eg. `offcanvas-lg`), where it is not a dialog.
+    /// Requires Bootstrap 5.2.
+    #[prop_or_default]
+    pub responsive: Option<ContainerSize>,
+}
+
+/// Media query matching screens from the breakpoint of a responsive [Offcanvas],
+/// where it is displayed in the page. `None` if it is always an offcanvas.
+fn breakpoint_query(size: &ContainerSize) -> Option<&'static str> {
+    match size {
+        ContainerSize::ExtraSmall => None,
+        ContainerSize::Small => Some("(min-width: 576px)"),
+        ContainerSize::Medium => Some("(min-width: 768px)"),
+        ContainerSize::Large => Some("(min-width: 992px)"),
+        ContainerSize::ExtraLarge => Some("(min-width: 1200px)"),
+        ContainerSize::ExtraExtraLarge => Some("(min-width: 1400px)"),
+    }
+}
+
+/// Does the media `query` match, updated when it changes. `false` without query.
+#[hook]
+fn use_media_query(query: Option<&'static str>) -> bool {
+    let matches = use_state_eq(|| {
+        query.and_then(|query| gloo_utils::window().match_media(query).ok().flatten())
+            .is_some_and(|list| list.matches())
+    });
+    {
+        let matches = matches.clone();
+        use_effect_with(query, move |query| {
+            let listener = query.and_then(|query| gloo_utils::window().match_media(query).ok().flatten()).map(|list| {
+                matches.set(list.matches());
+                let target = list.clone();
+                EventListener::new(&target, "change", move |_| matches.set(list.matches()))
+            });
+            move || drop(listener)
+        });
+    }
+    *matches
+}
+
+/// Attributes of an [Offcanvas]: tabindex, role, aria-modal and aria-hidden.
+///
+/// A responsive offcanvas displayed in the page (`inline`) is not a dialog and has
+/// none of them, so that its content stays available to assistive technologies.
+fn dialog_attributes(visible: bool, inline: bool) -> [Option<&'static str>; 4] {
+    if inline {
+        return [None; 4];
+    }
+    [
+        Some("-1"),
+        visible.then_some("dialog"),
+        visible.then_some("true"),
+        (!visible).then_some("true"),
+    ]
+}
+
+/// # Offcanvas component
+/// Hidden sidebar sliding from an edge of the viewport, parent of [OffcanvasHeader] and
+/// [OffcanvasBody].
+///
+/// Like [crate::component::Tooltip], this component is implemented with Yew instead of
+/// Bootstrap's JavaScript: visibility is controlled with the `show` property, and
+/// `on_hide` is called when the user requests to hide the offcanvas.
+///
+/// See [OffcanvasProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Button, Offcanvas, OffcanvasBody, OffcanvasHeader, OffcanvasPlacement};
+/// use yew_bootstrap::util::Color;
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let show = use_state(|| false);
+///     let onclick = { let show = show.clone(); Callback::from(move |_| show.set(true)) };
+///     let on_hide = { let show = show.clone(); Callback::from(move |_| show.set(false)) };
+///
+///     html!{
+///         <>
+///             <Button style={Color::Primary} {onclick}>{"Open"}</Button>
+///             <Offcanvas show={*show} {on_hide} placement={OffcanvasPlacement::End}>
+///                 <OffcanvasHeader title="Offcanvas" />
+///                 <OffcanvasBody>{"Content of the offcanvas"}</OffcanvasBody>
+///             </Offcanvas>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Offcanvas(props: &OffcanvasProps) -> Html {
+    let offcanvas_ref = use_node_ref();
+    let stage = use_transition(props.show, MODAL_DURATION, offcanvas_ref.clone(), true);
+    let backdrop_ref = use_node_ref();
+    let backdrop_stage = use_transition(
+        props.show && props.backdrop != ModalBackdrop::None,
+        FADE_DURATION,
+        backdrop_ref.clone(),
+        true,
+    );
+    // A responsive offcanvas is displayed in the page from its breakpoint
+    let inline = use_media_query(props.responsive.as_ref().and_then(breakpoint_query));
+    use_body_scroll_lock(stage.is_visible() && !inline && !props.scroll);
+
+    // Move focus to the offcanvas when opened, to receive keyboard events
+    {
+        let offcanvas_ref = offcanvas_ref.clone();
+        use_effect_with(stage.is_visible(), move |visible| {
+            if *visible {
+                if let Some(element) = offcanvas_ref.cast::<HtmlElement>() {
+                    let _ = element.focus();
+                }
+            }
+        });
+    }
+
+    let onkeydown = {
+        let on_hide = props.on_hide.clone();
+        let keyboard = props.keyboard;
+        Callback::from(move |event: KeyboardEvent| {
+            if keyboard && event.key() == "Escape" {
+                event.prevent_default();
+                on_hide.emit(());
+            }
+        })
+    };
+
+    let on_backdrop_click = {
+        let on_hide = props.on_hide.clone();
+        let backdrop = props.backdrop;
+        Callback::from(move |_: MouseEvent| {
+            if backdrop == ModalBackdrop::Normal {
+                on_hide.emit(());
+            }
+        })
+    };
+
+    let mut classes = match &props.responsive {
+        Some(size) if *size != ContainerSize::ExtraSmall => classes!(format!("offcanvas-{}", size.to_string())),
+        _ => classes!("offcanvas"),
+    };
+    classes.push(props.placement.class());
+    if stage.is_shown() {
+        classes.push("show");
+    }
+    classes.push(props.class.clone());
+
+    let mut backdrop_classes = classes!("offcanvas-backdrop", "fade");
+    if backdrop_stage.is_shown() {
+        backdrop_classes.push("show");
+    }
+
+    let context = OffcanvasContext { hide: props.on_hide.clone() };
+    let [tabindex, role, aria_modal, aria_hidden] = dialog_attributes(stage.is_visible(), inline);
+
+    html! {
+        <>
+            <div
+                ref={offcanvas_ref}
+                class={classes}
+                {tabindex}
+                id={props.id.clone()}
+                style={stage.is_visible().then_some("visibility: visible;")}
+                {role}
+                aria-modal={aria_modal}
+                aria-hidden={aria_hidden}
+                {onkeydown}
+            >
+                <ContextProvider<OffcanvasContext> {context}>
+                    { for props.children.iter() }
+                </ContextProvider<OffcanvasContext>>
+            </div>
+            if backdrop_stage.is_visible() {
+                <div ref={backdrop_ref} class={backdrop_classes} onclick={on_backdrop_click}></div>
+            }
+        </>
+    }
+}
+
+/// # Properties of [OffcanvasHeader]
+#[derive(Properties, Clone, PartialEq)]
+pub struct OffcanvasHeaderProps {
+    /// Title of the offcanvas
+    #[prop_or_default]
+    pub title: AttrValue,
+
+    /// Inner components, displayed after the title
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Show a button to hide the [Offcanvas], default true
+    #[prop_or(true)]
+    pub close_button: bool,
+}
+
+/// # Header of an [Offcanvas]
+///
+/// See [OffcanvasHeaderProps] for a listing of properties.
+#[function_component]
+pub fn OffcanvasHeader(props: &OffcanvasHeaderProps) -> Html {
+    let context = use_context::<OffcanvasContext>();
+
+    html! {
+        <div class={classes!("offcanvas-header", props.class.clone())}>
+            <h5 class="offcanvas-title">{ props.title.clone() }</h5>
+            { for props.children.iter() }
+            if props.close_button {
+                <button
+                    type="button"
+                    class="btn-close"
+                    aria-label="Close"
+                    onclick={context.map(|context| context.hide.reform(|_| ()))}
+                ></button>
+            }
+        </div>
+    }
+}
+
+/// # Properties of [OffcanvasBody]
+#[derive(Properties, Clone, PartialEq)]
+pub struct OffcanvasBodyProps {
+    /// Inner components
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Body of an [Offcanvas]
+///
+/// See [OffcanvasBodyProps] for a listing of properties.
+#[function_component]
+pub fn OffcanvasBody(props: &OffcanvasBodyProps) -> Html {
+    html! {
+        <div class={classes!("offcanvas-body", props.class.clone())}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dialog_attributes_only_for_offcanvas() {
+        assert_eq!(dialog_attributes(true, false), [Some("-1"), Some("dialog"), Some("true"), None]);
+        assert_eq!(dialog_attributes(false, false), [Some("-1"), None, None, Some("true")]);
+        assert_eq!(dialog_attributes(false, true), [None; 4]);
+        assert_eq!(dialog_attributes(true, true), [None; 4]);
+    }
+
+    #[test]
+    fn breakpoint_queries() {
+        assert_eq!(breakpoint_query(&ContainerSize::ExtraSmall), None);
+        assert_eq!(breakpoint_query(&ContainerSize::Large), Some("(min-width: 992px)"));
+    }
+}
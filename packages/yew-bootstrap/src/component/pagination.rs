@@ -0,0 +1,274 @@
+use yew::prelude::*;
+
+/// Item displayed by a [Pagination], see [pagination_window]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaginationItem {
+    /// Link to a page, 1-based
+    Page(usize),
+    /// Hidden pages
+    Ellipsis,
+}
+
+/// # Visible pages of a [Pagination]
+///
+/// Computes the items displayed for `total_pages` pages when `current` is active
+/// (both 1-based). The first page, the last page, and `siblings` pages on each side
+/// of the current page are always visible, other pages are replaced by
+/// [PaginationItem::Ellipsis].
+///
+/// The number of items doesn't depend on `current`, so the pagination doesn't
+/// change size while navigating.
+///
+/// ```rust
+/// use yew_bootstrap::component::{pagination_window, PaginationItem::*};
+///
+/// assert_eq!(pagination_window(10, 5, 1), vec![Page(1), Ellipsis, Page(4), Page(5), Page(6), Ellipsis, Page(10)]);
+/// assert_eq!(pagination_window(10, 2, 1), vec![Page(1), Page(2), Page(3), Page(4), Page(5), Ellipsis, Page(10)]);
+/// ```
+pub fn pagination_window(total_pages: usize, current: usize, siblings: usize) -> Vec<PaginationItem> {
+    // First, last, current and two ellipsis
+    let max_items = 2 * siblings + 5;
+    if total_pages <= max_items {
+        return (1..=total_pages).map(PaginationItem::Page).collect();
+    }
+
+    let current = current.clamp(1, total_pages);
+    let left = current.saturating_sub(siblings).max(1);
+    let right = (current + siblings).min(total_pages);
+    // An ellipsis is only useful if it hides at least two pages
+    let left_ellipsis = left > 3;
+    let right_ellipsis = right + 2 < total_pages;
+    let side_items = max_items - 2;
+
+    let mut items = Vec::with_capacity(max_items);
+    match (left_ellipsis, right_ellipsis) {
+        (false, _) => {
+            items.extend((1..=side_items).map(PaginationItem::Page));
+            items.push(PaginationItem::Ellipsis);
+            items.push(PaginationItem::Page(total_pages));
+        }
+        (true, false) => {
+            items.push(PaginationItem::Page(1));
+            items.push(PaginationItem::Ellipsis);
+            items.extend((total_pages - side_items + 1..=total_pages).map(PaginationItem::Page));
+        }
+        (true, true) => {
+            items.push(PaginationItem::Page(1));
+            items.push(PaginationItem::Ellipsis);
+            items.extend((left..=right).map(PaginationItem::Page));
+            items.push(PaginationItem::Ellipsis);
+            items.push(PaginationItem::Page(total_pages));
+        }
+    }
+    items
+}
+
+/// Size of a [Pagination]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PaginationSize {
+    Small,
+    #[default]
+    Normal,
+    Large,
+}
+
+/// Horizontal alignment of a [Pagination]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PaginationAlignment {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+/// # Properties of [Pagination]
+#[derive(Properties, Clone, PartialEq)]
+pub struct PaginationProps {
+    /// Number of pages
+    pub total_pages: usize,
+
+    /// Active page, 1-based
+    pub current: usize,
+
+    /// Called with the requested page, 1-based. The application is responsible for
+    /// updating `current`.
+    #[prop_or_default]
+    pub on_page_change: Callback<usize>,
+
+    /// Number of pages displayed on each side of the current page, default 1
+    #[prop_or(1)]
+    pub siblings: usize,
+
+    /// Size of the pagination
+    #[prop_or_default]
+    pub size: PaginationSize,
+
+    /// Horizontal alignment of the pagination
+    #[prop_or_default]
+    pub alignment: PaginationAlignment,
+
+    /// Show the previous and next links, default true
+    #[prop_or(true)]
+    pub prev_next: bool,
+
+    /// Text of the previous link
+    #[prop_or(AttrValue::from("Previous"))]
+    pub prev_text: AttrValue,
+
+    /// Text of the next link
+    #[prop_or(AttrValue::from("Next"))]
+    pub next_text: AttrValue,
+
+    /// Accessible label of the navigation
+    #[prop_or(AttrValue::from("Page navigation"))]
+    pub label: AttrValue,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Pagination component
+/// Links to a series of pages, with an ellipsis for hidden pages. See
+/// [pagination_window] for the pages which are displayed.
+///
+/// See [PaginationProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Pagination, PaginationAlignment};
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let page = use_state(|| 1);
+///     let on_page_change = { let page = page.clone(); Callback::from(move |p| page.set(p)) };
+///
+///     html!{
+///         <Pagination total_pages={20} current={*page} {on_page_change} alignment={PaginationAlignment::Center} />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Pagination(props: &PaginationProps) -> Html {
+    let mut classes = classes!("pagination");
+    match props.size {
+        PaginationSize::Small => classes.push("pagination-sm"),
+        PaginationSize::Normal => (),
+        PaginationSize::Large => classes.push("pagination-lg"),
+    }
+    match props.alignment {
+        PaginationAlignment::Start => (),
+        PaginationAlignment::Center => classes.push("justify-content-center"),
+        PaginationAlignment::End => classes.push("justify-content-end"),
+    }
+    classes.push(props.class.clone());
+
+    let current = props.current;
+    let link = |page: usize, disabled: bool, content: Html| {
+        let on_page_change = props.on_page_change.clone();
+        let onclick = Callback::from(move |event: MouseEvent| {
+            event.prevent_default();
+            on_page_change.emit(page);
+        });
+        html! {
+            <li class={classes!("page-item", disabled.then_some("disabled"))}>
+                if disabled {
+                    <span class="page-link">{ content }</span>
+                } else {
+                    <a class="page-link" href="#" {onclick}>{ content }</a>
+                }
+            </li>
+        }
+    };
+
+    html! {
+        <nav aria-label={props.label.clone()}>
+            <ul class={classes}>
+                if props.prev_next {
+                    { link(current.saturating_sub(1), current <= 1, html! { props.prev_text.clone() }) }
+                }
+                {
+                    for pagination_window(props.total_pages, current, props.siblings).into_iter().map(|item| match item {
+                        PaginationItem::Page(page) if page == current => html! {
+                            <li class="page-item active" aria-current="page">
+                                <span class="page-link">{ page }</span>
+                            </li>
+                        },
+                        PaginationItem::Page(page) => link(page, false, html! { page }),
+                        PaginationItem::Ellipsis => html! {
+                            <li class="page-item disabled">
+                                <span class="page-link">{ "…" }</span>
+                            </li>
+                        },
+                    })
+                }
+                if props.prev_next {
+                    { link(current + 1, current >= props.total_pages, html! { props.next_text.clone() }) }
+                }
+            </ul>
+        </nav>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PaginationItem::*;
+    use super::*;
+
+    #[test]
+    fn test_window_all_pages() {
+        assert_eq!(pagination_window(0, 1, 1), vec![]);
+        assert_eq!(pagination_window(1, 1, 1), vec![Page(1)]);
+        assert_eq!(
+            pagination_window(7, 4, 1),
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5), Page(6), Page(7)]
+        );
+    }
+
+    #[test]
+    fn test_window_ellipsis() {
+        assert_eq!(
+            pagination_window(20, 1, 1),
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5), Ellipsis, Page(20)]
+        );
+        assert_eq!(
+            pagination_window(20, 4, 1),
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5), Ellipsis, Page(20)]
+        );
+        assert_eq!(
+            pagination_window(20, 5, 1),
+            vec![Page(1), Ellipsis, Page(4), Page(5), Page(6), Ellipsis, Page(20)]
+        );
+        assert_eq!(
+            pagination_window(20, 17, 1),
+            vec![Page(1), Ellipsis, Page(16), Page(17), Page(18), Page(19), Page(20)]
+        );
+        assert_eq!(
+            pagination_window(20, 20, 1),
+            vec![Page(1), Ellipsis, Page(16), Page(17), Page(18), Page(19), Page(20)]
+        );
+    }
+
+    #[test]
+    fn test_window_siblings() {
+        assert_eq!(pagination_window(20, 10, 0), vec![Page(1), Ellipsis, Page(10), Ellipsis, Page(20)]);
+        assert_eq!(
+            pagination_window(20, 10, 2),
+            vec![Page(1), Ellipsis, Page(8), Page(9), Page(10), Page(11), Page(12), Ellipsis, Page(20)]
+        );
+    }
+
+    #[test]
+    fn test_window_constant_length() {
+        for current in 1..=30 {
+            assert_eq!(pagination_window(30, current, 2).len(), 9);
+        }
+    }
+
+    #[test]
+    fn test_window_out_of_range() {
+        assert_eq!(pagination_window(20, 0, 1), pagination_window(20, 1, 1));
+        assert_eq!(pagination_window(20, 99, 1), pagination_window(20, 20, 1));
+    }
+}
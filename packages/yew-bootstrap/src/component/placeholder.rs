@@ -0,0 +1,363 @@
+use gloo_console::warn;
+use yew::prelude::*;
+
+use super::card::{Card, CardBody};
+use super::{ListGroup, ListGroupItem, ListGroupVariant};
+use crate::util::Color;
+
+/// Height of a [Placeholder]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlaceholderSize {
+    /// Extra small, `placeholder-xs`
+    ExtraSmall,
+    /// Small, `placeholder-sm`
+    Small,
+    /// Height of the surrounding text
+    #[default]
+    Default,
+    /// Large, `placeholder-lg`
+    Large,
+}
+
+/// Animation of placeholders, see [PlaceholderGlow] and [PlaceholderWave]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlaceholderAnimation {
+    /// No animation
+    None,
+    /// Placeholders pulse, `placeholder-glow`
+    #[default]
+    Glow,
+    /// A wave goes through placeholders, `placeholder-wave`
+    Wave,
+}
+
+impl PlaceholderAnimation {
+    fn class(self) -> Option<&'static str> {
+        match self {
+            PlaceholderAnimation::None => None,
+            PlaceholderAnimation::Glow => Some("placeholder-glow"),
+            PlaceholderAnimation::Wave => Some("placeholder-wave"),
+        }
+    }
+}
+
+/// Widths (out of 12) of `lines` lines of placeholder text, varied like a
+/// paragraph, the last line being shorter
+fn text_line_widths(lines: usize) -> Vec<u8> {
+    const WIDTHS: [u8; 5] = [12, 10, 11, 9, 12];
+    (0..lines)
+        .map(|index| if index + 1 == lines && lines > 1 { 6 } else { WIDTHS[index % WIDTHS.len()] })
+        .collect()
+}
+
+/// # Properties of [Placeholder]
+#[derive(Properties, Clone, PartialEq)]
+pub struct PlaceholderProps {
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Width (out of 12) of the placeholder, as a `col-*` class. Use `class` for
+    /// other widths, like `w-75`.
+    #[prop_or_default]
+    pub width: Option<u8>,
+
+    /// Height of the placeholder, default [PlaceholderSize::Default]
+    #[prop_or_default]
+    pub size: PlaceholderSize,
+
+    /// Optional color style, the current text color is used if unset
+    #[prop_or_default]
+    pub style: Option<Color>,
+}
+
+/// # Placeholder component
+/// Gray block standing for content which is still loading.
+///
+/// Placeholders are sized like text, and are animated when inside a
+/// [PlaceholderGlow] or [PlaceholderWave]. For common layouts, see
+/// [PlaceholderText], [PlaceholderCard] and [PlaceholderListGroup].
+///
+/// See [PlaceholderProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Placeholder, PlaceholderGlow, PlaceholderSize};
+/// use yew_bootstrap::util::Color;
+///
+/// fn test() -> Html {
+///     html!{
+///         <PlaceholderGlow>
+///             <Placeholder width={6} />
+///             <Placeholder width={4} size={PlaceholderSize::Large} style={Color::Primary} />
+///         </PlaceholderGlow>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Placeholder(props: &PlaceholderProps) -> Html {
+    let mut classes = classes!("placeholder");
+    if let Some(width) = props.width {
+        if width > 12 {
+            warn!("Placeholder `width` cannot be greater than 12");
+        }
+        classes.push(format!("col-{}", width));
+    }
+    match props.size {
+        PlaceholderSize::ExtraSmall => classes.push("placeholder-xs"),
+        PlaceholderSize::Small => classes.push("placeholder-sm"),
+        PlaceholderSize::Default => (),
+        PlaceholderSize::Large => classes.push("placeholder-lg"),
+    }
+    if let Some(color) = &props.style {
+        classes.push(format!("bg-{}", color));
+    }
+    classes.push(props.class.clone());
+
+    html! {
+        <span class={classes} aria-hidden="true"></span>
+    }
+}
+
+/// # Properties of [PlaceholderGlow] and [PlaceholderWave]
+#[derive(Properties, Clone, PartialEq)]
+pub struct PlaceholderAnimationProps {
+    /// Placeholders to animate, and any other content
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Glowing placeholders
+/// Container animating the [Placeholder] components inside it with a pulse.
+///
+/// See [PlaceholderAnimationProps] for a listing of properties, and [Placeholder]
+/// for an example.
+#[function_component]
+pub fn PlaceholderGlow(props: &PlaceholderAnimationProps) -> Html {
+    html! {
+        <div class={classes!("placeholder-glow", props.class.clone())}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// # Waving placeholders
+/// Container animating the [Placeholder] components inside it with a wave.
+///
+/// See [PlaceholderAnimationProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Placeholder, PlaceholderWave};
+///
+/// fn test() -> Html {
+///     html!{
+///         <PlaceholderWave class="h5">
+///             <Placeholder width={8} />
+///         </PlaceholderWave>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn PlaceholderWave(props: &PlaceholderAnimationProps) -> Html {
+    html! {
+        <div class={classes!("placeholder-wave", props.class.clone())}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// # Properties of [PlaceholderText]
+#[derive(Properties, Clone, PartialEq)]
+pub struct PlaceholderTextProps {
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Number of lines, default 3
+    #[prop_or(3)]
+    pub lines: usize,
+
+    /// Animation, default [PlaceholderAnimation::Glow]
+    #[prop_or_default]
+    pub animation: PlaceholderAnimation,
+
+    /// Height of the lines, default [PlaceholderSize::Default]
+    #[prop_or_default]
+    pub size: PlaceholderSize,
+}
+
+/// # Placeholder for text
+/// Paragraph of [Placeholder] lines of varied widths.
+///
+/// See [PlaceholderTextProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{PlaceholderAnimation, PlaceholderText};
+///
+/// fn test() -> Html {
+///     html!{
+///         <PlaceholderText lines={4} animation={PlaceholderAnimation::Wave} />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn PlaceholderText(props: &PlaceholderTextProps) -> Html {
+    html! {
+        <p class={classes!(props.animation.class(), props.class.clone())}>
+            {
+                for text_line_widths(props.lines).into_iter().map(|width| html! {
+                    <Placeholder width={width} size={props.size} />
+                })
+            }
+        </p>
+    }
+}
+
+/// # Properties of [PlaceholderCard]
+#[derive(Properties, Clone, PartialEq)]
+pub struct PlaceholderCardProps {
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Animation, default [PlaceholderAnimation::Glow]
+    #[prop_or_default]
+    pub animation: PlaceholderAnimation,
+
+    /// Height of an image placeholder on top of the card, as a CSS length. No
+    /// image if unset.
+    #[prop_or_default]
+    pub image_height: Option<AttrValue>,
+
+    /// Number of lines of text, default 3
+    #[prop_or(3)]
+    pub lines: usize,
+
+    /// Color of a button placeholder at the bottom of the card. No button if unset.
+    #[prop_or_default]
+    pub button: Option<Color>,
+}
+
+/// # Placeholder for a card
+/// [Card] with a title, lines of text, and optionally an image and a button,
+/// standing for a card which is still loading.
+///
+/// See [PlaceholderCardProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::PlaceholderCard;
+/// use yew_bootstrap::util::Color;
+///
+/// fn test() -> Html {
+///     html!{
+///         <PlaceholderCard image_height="180px" button={Color::Primary} />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn PlaceholderCard(props: &PlaceholderCardProps) -> Html {
+    let animation = props.animation.class();
+
+    html! {
+        <Card class={props.class.clone()}>
+            if let Some(height) = &props.image_height {
+                <div class={classes!("card-img-top", animation)} style={format!("height: {height};")}>
+                    <Placeholder class="w-100 h-100" />
+                </div>
+            }
+            <CardBody>
+                <h5 class={classes!("card-title", animation)}>
+                    <Placeholder width={6} />
+                </h5>
+                <p class={classes!("card-text", animation)}>
+                    {
+                        for text_line_widths(props.lines).into_iter().map(|width| html! {
+                            <Placeholder width={width} />
+                        })
+                    }
+                </p>
+                if let Some(color) = &props.button {
+                    <span class={classes!("btn", format!("btn-{}", color), "disabled", "placeholder", "col-6")} aria-hidden="true"></span>
+                }
+            </CardBody>
+        </Card>
+    }
+}
+
+/// # Properties of [PlaceholderListGroup]
+#[derive(Properties, Clone, PartialEq)]
+pub struct PlaceholderListGroupProps {
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Number of items, default 3
+    #[prop_or(3)]
+    pub items: usize,
+
+    /// Animation, default [PlaceholderAnimation::Glow]
+    #[prop_or_default]
+    pub animation: PlaceholderAnimation,
+
+    /// Display variant of the list, see [ListGroupVariant]
+    #[prop_or_default]
+    pub variant: ListGroupVariant,
+}
+
+/// # Placeholder for a list group
+/// [ListGroup] whose items are [Placeholder] lines, standing for a list which is
+/// still loading.
+///
+/// See [PlaceholderListGroupProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{PlaceholderAnimation, PlaceholderListGroup};
+///
+/// fn test() -> Html {
+///     html!{
+///         <PlaceholderListGroup items={5} animation={PlaceholderAnimation::Wave} />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn PlaceholderListGroup(props: &PlaceholderListGroupProps) -> Html {
+    let animation = props.animation.class();
+
+    html! {
+        <ListGroup class={props.class.clone()} variant={props.variant}>
+            {
+                for text_line_widths(props.items).into_iter().map(|width| html_nested! {
+                    <ListGroupItem class={classes!(animation)}>
+                        <Placeholder width={width.min(8)} />
+                    </ListGroupItem>
+                })
+            }
+        </ListGroup>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_line_widths_shortens_last_line() {
+        assert_eq!(text_line_widths(0), Vec::<u8>::new());
+        assert_eq!(text_line_widths(1), vec![12]);
+        assert_eq!(text_line_widths(3), vec![12, 10, 6]);
+        assert_eq!(text_line_widths(7), vec![12, 10, 11, 9, 12, 12, 6]);
+    }
+}
@@ -0,0 +1,280 @@
+//! Implements popover support, on top of the [Tooltip][crate::component::Tooltip]
+//! machinery.
+//!
+//! * <https://github.com/react-bootstrap/react-bootstrap/blob/master/src/Popover.tsx>
+//! * <https://github.com/twbs/bootstrap/blob/main/js/src/popover.js>
+
+use gloo_events::EventListener;
+use popper_rs::{
+    prelude::{use_popper, Modifier, Offset, Options, Placement, Strategy},
+    state::ApplyAttributes,
+};
+use wasm_bindgen::JsCast;
+use web_sys::Node;
+use yew::{platform::spawn_local, prelude::*};
+
+use super::{use_described_by, use_focus_hover, use_focus_should_trigger, TooltipFocusTrigger};
+use crate::util::{use_transition, FADE_DURATION};
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct PopoverProps {
+    /// The node which this popover is attached to.
+    ///
+    /// If the `target` can be `disabled`, pass the same value to
+    /// [Popover's `disabled` property][Self::disabled], like for
+    /// [Tooltip][crate::component::Tooltip].
+    pub target: NodeRef,
+
+    /// ID of the popover.
+    ///
+    /// If this is set, [Popover] will set the `target`'s `aria-describedby`
+    /// attribute whenever it is visible.
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+
+    /// Title of the popover, displayed in its header. There is no header if unset.
+    #[prop_or_default]
+    pub title: Option<AttrValue>,
+
+    /// Content of the popover body, supports arbitrary HTML.
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Placement of the popover, default [Placement::Right].
+    ///
+    /// [Popper's website shows all placement options][0].
+    ///
+    /// [0]: https://popper.js.org/
+    #[prop_or(Placement::Right)]
+    pub placement: Placement,
+
+    /// Use fade transition when showing or hiding the popover.
+    #[prop_or_default]
+    pub fade: bool,
+
+    /// If `true`, always show the popover, regardless of other triggers.
+    ///
+    /// [`disabled = true`][PopoverProps::disabled] overrides this option.
+    #[prop_or_default]
+    pub show: bool,
+
+    /// Toggle the popover when the [`target`][Self::target] is clicked.
+    ///
+    /// This defaults to `true`, like Bootstrap's popovers.
+    #[prop_or(true)]
+    pub trigger_on_click: bool,
+
+    /// Show the popover when the [`target`][Self::target] node recieves input
+    /// or keyboard focus.
+    ///
+    /// This defaults to [`TooltipFocusTrigger::Never`]. See
+    /// [`TooltipFocusTrigger`] for other options and their caveats.
+    #[prop_or(TooltipFocusTrigger::Never)]
+    pub trigger_on_focus: TooltipFocusTrigger,
+
+    /// Show the popover when the [`target`][Self::target] component has the
+    /// mouse cursor hovered over it, default `false`.
+    #[prop_or_default]
+    pub trigger_on_hover: bool,
+
+    /// Hide a popover opened by a click when clicking anywhere outside of the
+    /// popover and its [`target`][Self::target], default `true`.
+    #[prop_or(true)]
+    pub dismiss_on_outside_click: bool,
+
+    /// Called when the popover is opened (`true`) or closed (`false`) by a click.
+    #[prop_or_default]
+    pub on_toggle: Callback<bool>,
+
+    /// If `true`, always hide the popover. *This overrides all other
+    /// conditions.*
+    ///
+    /// The popover will remain part of the DOM.
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// # Popover component
+///
+/// Overlay with a header and arbitrary content, which is toggled by clicking an
+/// element.
+///
+/// This component uses the same implementation as [Tooltip][crate::component::Tooltip],
+/// see its documentation for the differences with Bootstrap's implementation.
+/// Unlike tooltips, popovers are triggered by clicks by default, and can
+/// contain interactive components.
+///
+/// The `focus` and `hover` triggers can also be enabled, or the popover can be
+/// displayed manually with the [`show`][PopoverProps::show] property.
+///
+/// ## Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Button, Placement, Popover};
+/// use yew_bootstrap::util::Color;
+///
+/// fn test() -> Html {
+///     let btn_ref = NodeRef::default();
+///     html! {
+///         <>
+///             <Button style={Color::Danger} node_ref={btn_ref.clone()}>
+///                 {"Click to toggle popover"}
+///             </Button>
+///             <Popover target={btn_ref} title="Popover title" placement={Placement::Bottom} id="popover">
+///                 {"And here's some "}<strong>{"amazing"}</strong>{" content."}
+///             </Popover>
+///         </>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Popover(props: &PopoverProps) -> Html {
+    let popover_ref = use_node_ref();
+
+    let options = use_memo(props.placement, |placement| Options {
+        placement: *placement,
+        modifiers: vec![Modifier::Offset(Offset {
+            skidding: 0,
+            distance: 8,
+        })],
+        strategy: Strategy::Fixed,
+    });
+
+    let popper = use_popper(props.target.clone(), popover_ref.clone(), options).unwrap();
+
+    let (focused, hovered) = use_focus_hover(props.target.clone());
+    let focus_should_trigger = use_focus_should_trigger(props.trigger_on_focus);
+    let clicked = use_state_eq(|| false);
+
+    if props.disabled {
+        // Whenever this component is disabled, explicitly set our focus, hover
+        // and click state to false.
+        focused.set(false);
+        hovered.set(false);
+        clicked.set(false);
+    }
+
+    let show = !props.disabled
+        && (props.show
+            || (*clicked && props.trigger_on_click)
+            || (*focused && focus_should_trigger)
+            || (*hovered && props.trigger_on_hover));
+
+    let stage = use_transition(
+        show,
+        if props.fade { FADE_DURATION } else { 0 },
+        popover_ref.clone(),
+        true,
+    );
+
+    // Toggle on clicks on the target
+    {
+        let clicked = clicked.clone();
+        let on_toggle = props.on_toggle.clone();
+        use_effect_with(
+            (props.target.clone(), props.trigger_on_click, *clicked),
+            move |(target_ref, trigger_on_click, current)| {
+                let current = *current;
+                let listener = target_ref
+                    .get()
+                    .filter(|_| *trigger_on_click)
+                    .map(|target| {
+                        EventListener::new(&target, "click", move |_| {
+                            clicked.set(!current);
+                            on_toggle.emit(!current);
+                        })
+                    });
+                move || drop(listener)
+            },
+        );
+    }
+
+    // Dismiss on clicks outside of the popover and its target
+    {
+        let clicked = clicked.clone();
+        let on_toggle = props.on_toggle.clone();
+        let target_ref = props.target.clone();
+        let popover_ref = popover_ref.clone();
+        use_effect_with(
+            (*clicked && props.dismiss_on_outside_click, props.target.clone()),
+            move |(listen, _)| {
+                let listener = listen.then(|| {
+                    EventListener::new(&gloo_utils::document(), "click", move |event| {
+                        let Some(node) = event.target().and_then(|target| target.dyn_into::<Node>().ok()) else {
+                            return;
+                        };
+                        let inside = [&target_ref, &popover_ref]
+                            .into_iter()
+                            .any(|r| r.get().is_some_and(|element| element.contains(Some(&node))));
+                        if !inside {
+                            clicked.set(false);
+                            on_toggle.emit(false);
+                        }
+                    })
+                });
+                move || drop(listener)
+            },
+        );
+    }
+
+    use_effect_with((show, popper.instance.clone()), |(show, popper)| {
+        if *show {
+            let popper = popper.clone();
+
+            spawn_local(async move {
+                popper.update().await;
+            });
+        }
+    });
+
+    use_effect_with(
+        (popover_ref.clone(), popper.state.attributes.popper.clone()),
+        |(popover_ref, attributes)| {
+            popover_ref.apply_attributes(attributes);
+        },
+    );
+
+    use_described_by(props.target.clone(), props.id.clone(), show);
+
+    let mut class = classes!["popover", "bs-popover-auto"];
+    if props.fade {
+        class.push("fade");
+    }
+    if stage.is_shown() {
+        class.push("show");
+    }
+
+    let mut popper_style = popper.state.styles.popper.clone();
+    if !stage.is_visible() {
+        // Unlike tooltips, popovers are not transparent without the `show` class.
+        // Make sure the hidden `<Popover>` doesn't interfere with other elements.
+        popper_style.insert("visibility".to_string(), "hidden".to_string());
+        popper_style.insert("pointer-events".to_string(), "none".to_string());
+    }
+
+    create_portal(
+        html_nested! {
+            <div
+                ref={&popover_ref}
+                role="tooltip"
+                {class}
+                style={&popper_style}
+                id={props.id.clone()}
+            >
+                <div
+                    class="popover-arrow"
+                    data-popper-arrow="true"
+                    style={&popper.state.styles.arrow}
+                />
+                if let Some(title) = &props.title {
+                    <h3 class="popover-header">{ title.clone() }</h3>
+                }
+                <div class="popover-body">
+                    { for props.children.iter() }
+                </div>
+            </div>
+        },
+        gloo_utils::body().into(),
+    )
+}
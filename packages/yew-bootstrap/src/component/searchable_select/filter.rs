@@ -13,7 +13,7 @@ use super::SearchableSelect;
 /// - option (&SOption): Option to filter
 ///
 /// The function returns `true` if the option needs to be kept
-type FilterFnInner = dyn Fn(AttrValue, &SOption) -> bool;
+type FilterFnInner<T> = dyn Fn(AttrValue, &SOption<T>) -> bool;
 
 /// # Filtering structure for [SearchableSelect]
 ///
@@ -57,18 +57,46 @@ type FilterFnInner = dyn Fn(AttrValue, &SOption) -> bool;
 ///
 /// And use `filter = my_filter` in [SearchableSelect] component.
 ///
-#[derive(Clone)]
-pub struct FilterFn (
+/// For a [SearchableSelect] with values of another type than [AttrValue], use
+/// [FilterFn::icase] and [FilterFn::case] instead.
+///
+/// To sort the options by relevance and highlight the matched characters, use a
+/// [ScoreFn] instead. It can also be converted into a [FilterFn].
+pub struct FilterFn<T = AttrValue> (
     /// Internal function, inside [Rc] for cheap Clone implementation.
-    pub Rc<FilterFnInner>
+    pub Rc<FilterFnInner<T>>
 );
 
-impl PartialEq for FilterFn {
+impl<T> Clone for FilterFn<T> {
+    fn clone(&self) -> Self {
+        FilterFn(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for FilterFn<T> {
     fn eq(&self, other: &Self) -> bool {
         Rc::ptr_eq(&self.0, &other.0)
     }
 }
 
+impl<T: 'static> FilterFn<T> {
+    /// Filter options whose title contains the searched value, case insensitive.
+    /// See [filter_icase].
+    pub fn icase() -> Self {
+        FilterFn::from(|value: AttrValue, option: &SOption<T>| {
+            option.title.to_lowercase().contains(&value.to_lowercase())
+        })
+    }
+
+    /// Filter options whose title contains the searched value, case sensitive.
+    /// See [filter_case].
+    pub fn case() -> Self {
+        FilterFn::from(|value: AttrValue, option: &SOption<T>| {
+            option.title.as_ref().contains(value.as_ref())
+        })
+    }
+}
+
 /// Implement `From` to help creation of `FilterFn`. Example:
 ///
 /// ```rust
@@ -79,9 +107,9 @@ impl PartialEq for FilterFn {
 ///   option.title.starts_with(value.as_str())
 /// );
 /// ```
-impl<F> From<F> for FilterFn
+impl<T, F> From<F> for FilterFn<T>
 where
-    F: Fn(AttrValue, &SOption) -> bool + 'static,
+    F: Fn(AttrValue, &SOption<T>) -> bool + 'static,
 {
     fn from(f: F) -> Self {
         FilterFn(Rc::new(f))
@@ -93,9 +121,7 @@ where
 /// Returns true if option contains provided value (case insensitive). This is the
 /// default filter function inside [SearchableSelect] component.
 pub fn filter_icase() -> FilterFn {
-    FilterFn::from(|value: AttrValue, option: &SOption| {
-        option.title.to_lowercase().contains(&value.to_lowercase())
-    })
+    FilterFn::icase()
 }
 
 /// # Filter function (Case sensitive)
@@ -104,8 +130,258 @@ pub fn filter_icase() -> FilterFn {
 ///
 /// To use it inside [SearchableSelect], set `filter = filter_case()`.
 pub fn filter_case() -> FilterFn {
-    FilterFn::from(|value: AttrValue, option: &SOption| {
-        option.title.as_ref().contains(value.as_ref())
+    FilterFn::case()
+}
+
+/// # Match of an option by a [ScoreFn]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FilterMatch {
+    /// Relevance of the option, higher is better
+    pub score: i32,
+
+    /// Indices of the characters (not bytes) of the title which matched the searched
+    /// value, highlighted in the list of options
+    pub positions: Vec<usize>,
+}
+
+/// Type of the function inside [ScoreFn] structure.
+///
+/// Arguments are the same as for [FilterFn]. The function returns `None` if the
+/// option needs to be removed, or how well it matches otherwise.
+type ScoreFnInner<T> = dyn Fn(AttrValue, &SOption<T>) -> Option<FilterMatch>;
+
+/// # Scoring structure for [SearchableSelect]
+///
+/// Structure containing a function both filtering and scoring the options, used in
+/// the `score` property of [SearchableSelect] instead of a [FilterFn]. The matching
+/// options are sorted by decreasing score inside their group, and the matched
+/// characters are highlighted.
+///
+/// These functions are provided, all ignoring case and accents (`"creme"` matches
+/// `"Crème"`):
+///
+/// - [score_fuzzy]: the searched characters appear in the title in the same
+///   order, not necessarily next to each other. Consecutive characters and
+///   characters starting words rank higher.
+/// - [score_word_prefix]: each searched word starts a word of the title
+/// - [score_unaccent]: the title contains the searched value
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{SearchableSelect, SOption, score_fuzzy};
+///
+/// fn test() -> Html {
+///     html! {
+///         <SearchableSelect
+///             options={ vec![
+///                 SOption::new(AttrValue::from("1"), "Crème brûlée"),
+///                 SOption::new(AttrValue::from("2"), "Chocolate mousse"),
+///             ]}
+///             placeholder="Select a dessert..."
+///             onselectchange={ Callback::default() }
+///             score={ score_fuzzy() }
+///         />
+///     }
+/// }
+/// ```
+///
+/// A custom function can also be created with `ScoreFn::from`, returning a
+/// [FilterMatch].
+pub struct ScoreFn<T = AttrValue> (
+    /// Internal function, inside [Rc] for cheap Clone implementation.
+    pub Rc<ScoreFnInner<T>>
+);
+
+impl<T> Clone for ScoreFn<T> {
+    fn clone(&self) -> Self {
+        ScoreFn(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for ScoreFn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: 'static> ScoreFn<T> {
+    /// Fuzzy matching of the title, see [score_fuzzy]
+    pub fn fuzzy() -> Self {
+        ScoreFn::from(|value: AttrValue, option: &SOption<T>| {
+            let query: Vec<char> = value.chars().filter(|c| !c.is_whitespace()).map(fold).collect();
+            fuzzy_match(&option.title.chars().map(fold).collect::<Vec<_>>(), &query)
+        })
+    }
+
+    /// Matching of the start of the words of the title, see [score_word_prefix]
+    pub fn word_prefix() -> Self {
+        ScoreFn::from(|value: AttrValue, option: &SOption<T>| {
+            let words: Vec<Vec<char>> = value.split_whitespace().map(|word| word.chars().map(fold).collect()).collect();
+            word_prefix_match(&option.title.chars().map(fold).collect::<Vec<_>>(), &words)
+        })
+    }
+
+    /// Matching of a part of the title, see [score_unaccent]
+    pub fn unaccent() -> Self {
+        ScoreFn::from(|value: AttrValue, option: &SOption<T>| {
+            let query: Vec<char> = value.trim().chars().map(fold).collect();
+            substring_match(&option.title.chars().map(fold).collect::<Vec<_>>(), &query)
+        })
+    }
+}
+
+impl<T, F> From<F> for ScoreFn<T>
+where
+    F: Fn(AttrValue, &SOption<T>) -> Option<FilterMatch> + 'static,
+{
+    fn from(f: F) -> Self {
+        ScoreFn(Rc::new(f))
+    }
+}
+
+/// Keep the options matched by a [ScoreFn], ignoring the score
+impl<T: 'static> From<ScoreFn<T>> for FilterFn<T> {
+    fn from(score: ScoreFn<T>) -> Self {
+        FilterFn(Rc::new(move |value, option| score.0(value, option).is_some()))
+    }
+}
+
+/// # Score function (Fuzzy)
+///
+/// Matches options whose title contains the characters of the searched value in
+/// the same order, ignoring case, accents and spaces: `"cb"` matches
+/// `"Crème brûlée"`. Options with consecutive matched characters, or matched
+/// characters at the start of words, rank higher.
+///
+/// To use it inside [SearchableSelect], set `score = score_fuzzy()`.
+pub fn score_fuzzy() -> ScoreFn {
+    ScoreFn::fuzzy()
+}
+
+/// # Score function (Word prefix)
+///
+/// Matches options where each word of the searched value starts a different word
+/// of the title, ignoring case and accents: `"bru cre"` matches `"Crème brûlée"`.
+/// Options whose first words match rank higher.
+///
+/// To use it inside [SearchableSelect], set `score = score_word_prefix()`.
+pub fn score_word_prefix() -> ScoreFn {
+    ScoreFn::word_prefix()
+}
+
+/// # Score function (Accent insensitive)
+///
+/// Matches options whose title contains the searched value, ignoring case and
+/// accents: `"brulee"` matches `"Crème brûlée"`. Options where the match is closer
+/// to the start rank higher.
+///
+/// To use it inside [SearchableSelect], set `score = score_unaccent()`.
+pub fn score_unaccent() -> ScoreFn {
+    ScoreFn::unaccent()
+}
+
+/// Lower case character without accent, for Latin scripts
+fn fold(c: char) -> char {
+    match c.to_lowercase().next().unwrap_or(c) {
+        'à'..='æ' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'ĥ' | 'ħ' => 'h',
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'ĵ' => 'j',
+        'ķ' => 'k',
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' | 'œ' => 'o',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ß' => 's',
+        'ţ' | 'ť' | 'ŧ' => 't',
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'ŵ' => 'w',
+        'ý' | 'ÿ' | 'ŷ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        c => c,
+    }
+}
+
+/// The character at `index` starts a word
+fn is_word_start(title: &[char], index: usize) -> bool {
+    index == 0 || !title[index - 1].is_alphanumeric()
+}
+
+/// Best fuzzy match of `query` in `title`, both folded
+fn fuzzy_match(title: &[char], query: &[char]) -> Option<FilterMatch> {
+    let Some(first) = query.first() else {
+        return Some(FilterMatch::default());
+    };
+
+    let mut best: Option<FilterMatch> = None;
+    for start in (0..title.len()).filter(|index| title[*index] == *first) {
+        // Match the remaining characters as early as possible after the start
+        let mut positions = vec![start];
+        for c in &query[1..] {
+            let next = positions[positions.len() - 1] + 1;
+            match title[next..].iter().position(|t| t == c) {
+                Some(offset) => positions.push(next + offset),
+                None => break,
+            }
+        }
+        if positions.len() < query.len() {
+            // Later starts cannot match either
+            break;
+        }
+
+        let score = positions.iter().enumerate().map(|(k, position)| {
+            let word_start = if is_word_start(title, *position) { 12 } else { 0 };
+            let gap = match k {
+                0 => 0,
+                _ if *position == positions[k - 1] + 1 => 8,
+                _ => -((position - positions[k - 1] - 1) as i32),
+            };
+            16 + word_start + gap
+        }).sum::<i32>() - start as i32;
+
+        if best.as_ref().is_none_or(|best| score > best.score) {
+            best = Some(FilterMatch { score, positions });
+        }
+    }
+    best
+}
+
+/// Match of each word of `words` as the start of a different word of `title`, all
+/// folded
+fn word_prefix_match(title: &[char], words: &[Vec<char>]) -> Option<FilterMatch> {
+    let starts: Vec<usize> = (0..title.len())
+        .filter(|index| title[*index].is_alphanumeric() && is_word_start(title, *index))
+        .collect();
+
+    let mut used = Vec::new();
+    let mut result = FilterMatch::default();
+    for word in words {
+        let (rank, start) = starts.iter().enumerate().find(
+            |(rank, start)| !used.contains(rank) && title[**start..].starts_with(word)
+        )?;
+        used.push(rank);
+        result.score += 100 - rank as i32;
+        result.positions.extend(*start..*start + word.len());
+    }
+    result.positions.sort_unstable();
+    Some(result)
+}
+
+/// First occurrence of `query` in `title`, both folded
+fn substring_match(title: &[char], query: &[char]) -> Option<FilterMatch> {
+    if query.is_empty() {
+        return Some(FilterMatch::default());
+    }
+
+    let start = title.windows(query.len()).position(|window| window == query)?;
+    let word_start = if is_word_start(title, start) { 50 } else { 0 };
+    Some(FilterMatch {
+        score: 100 + word_start - start as i32,
+        positions: (start..start + query.len()).collect(),
     })
 }
 
@@ -118,7 +394,7 @@ pub fn filter_case() -> FilterFn {
 /// Return a vector of tuple containing:
 /// - original index in the list, as integer
 /// - the reference to the option
-pub (crate) fn filter_by_group(options: &[SOption], search: AttrValue, filter: FilterFn) -> Vec<(usize, &SOption)> {
+pub (crate) fn filter_by_group<T>(options: &[SOption<T>], search: AttrValue, filter: FilterFn<T>) -> Vec<(usize, &SOption<T>)> {
     let mut result = Vec::new();
     let mut current_group = Vec::new();
     let mut current_header = None;
@@ -150,6 +426,49 @@ pub (crate) fn filter_by_group(options: &[SOption], search: AttrValue, filter: F
     result
 }
 
+/// Score by group
+///
+/// Like [filter_by_group], but using a score function: inside each group, the
+/// options are sorted by decreasing score, keeping the original order for equal
+/// scores.
+///
+/// Return a vector of tuple containing:
+/// - original index in the list, as integer
+/// - the reference to the option
+/// - the indices of the matched characters in the title
+pub (crate) fn score_by_group<T>(options: &[SOption<T>], search: AttrValue, score: ScoreFn<T>) -> Vec<(usize, &SOption<T>, Vec<usize>)> {
+    let mut result = Vec::new();
+    let mut current_group: Vec<(usize, &SOption<T>, FilterMatch)> = Vec::new();
+    let mut current_header = None;
+
+    for (idx, option) in options.iter().enumerate() {
+        if option.header {
+            push_scored_group(&mut result, current_header, std::mem::take(&mut current_group));
+            current_header = Some((idx, option));
+        } else if let Some(filter_match) = score.0(search.clone(), option) {
+            current_group.push((idx, option, filter_match));
+        }
+    }
+    push_scored_group(&mut result, current_header, current_group);
+
+    result
+}
+
+/// Add a group of scored options, sorted by decreasing score, and its header to the
+/// result of [score_by_group], unless the group is empty
+#[allow(clippy::type_complexity)]
+fn push_scored_group<'a, T>(
+    result: &mut Vec<(usize, &'a SOption<T>, Vec<usize>)>,
+    header: Option<(usize, &'a SOption<T>)>,
+    mut group: Vec<(usize, &'a SOption<T>, FilterMatch)>,
+) {
+    if !group.is_empty() {
+        group.sort_by_key(|(_, _, filter_match)| -filter_match.score);
+        result.extend(header.map(|(idx, option)| (idx, option, Vec::new())));
+        result.extend(group.into_iter().map(|(idx, option, filter_match)| (idx, option, filter_match.positions)));
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -281,4 +600,84 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_fold() {
+        assert_eq!("Crème Brûlée Ñandú Łódź".chars().map(fold).collect::<String>(), "creme brulee nandu lodz");
+    }
+
+    #[test]
+    fn test_score_fuzzy() {
+        let score = score_fuzzy().0;
+        let option = |title: &'static str| SOption { title: title.into(), ..SOption::default() };
+
+        assert_eq!(score("".into(), &option("Apple")), Some(FilterMatch::default()));
+        assert_eq!(score("xyz".into(), &option("Apple")), None);
+        assert_eq!(score("pa".into(), &option("Apple")), None);
+        assert_eq!(score("cb".into(), &option("Crème brûlée")).unwrap().positions, vec![0, 6]);
+        assert_eq!(score("ELE".into(), &option("Crème brûlée")).unwrap().positions, vec![2, 9, 10]);
+
+        // Best start is chosen: consecutive characters at a word start
+        assert_eq!(score("ap".into(), &option("grape apple")).unwrap().positions, vec![6, 7]);
+
+        let consecutive = score("app".into(), &option("Apple")).unwrap().score;
+        let word_starts = score("app".into(), &option("A Pink Pear")).unwrap().score;
+        let scattered = score("app".into(), &option("Grapefruit pip")).unwrap().score;
+        assert!(consecutive > scattered);
+        assert!(word_starts > scattered);
+    }
+
+    #[test]
+    fn test_score_word_prefix() {
+        let score = score_word_prefix().0;
+        let option = |title: &'static str| SOption { title: title.into(), ..SOption::default() };
+
+        assert_eq!(score("  ".into(), &option("Apple")), Some(FilterMatch::default()));
+        assert_eq!(score("pple".into(), &option("Apple")), None);
+        assert_eq!(score("bru cre".into(), &option("Crème brûlée")).unwrap().positions, vec![0, 1, 2, 6, 7, 8]);
+        assert_eq!(score("cr cr".into(), &option("Crème brûlée")), None);
+        assert_eq!(score("pie".into(), &option("Apple-pie")).unwrap().positions, vec![6, 7, 8]);
+        assert!(score("app".into(), &option("Apple pie")).unwrap().score > score("pie".into(), &option("Apple pie")).unwrap().score);
+    }
+
+    #[test]
+    fn test_score_unaccent() {
+        let score = score_unaccent().0;
+        let option = |title: &'static str| SOption { title: title.into(), ..SOption::default() };
+
+        assert_eq!(score("brulee".into(), &option("Crème brûlée")).unwrap().positions, vec![6, 7, 8, 9, 10, 11]);
+        assert_eq!(score("cb".into(), &option("Crème brûlée")), None);
+        assert_eq!(score("Crème brûlée, vanilla".into(), &option("Crème brûlée")), None);
+        assert!(score("ap".into(), &option("Apple")).unwrap().score > score("ap".into(), &option("Grape")).unwrap().score);
+
+        let filter = FilterFn::from(score_unaccent());
+        assert!(filter.0("CREME".into(), &option("Crème brûlée")));
+        assert!(!filter.0("chocolate".into(), &option("Crème brûlée")));
+    }
+
+    #[test]
+    fn test_score_by_group() {
+        let options = vec![
+            SOption { title: "Pineapple".into(), ..SOption::default() },
+            SOption { title: "Apple".into(), ..SOption::default() },
+            SOption { title: "Group 1".into(), header: true, ..SOption::default() },
+            SOption { title: "Cherry".into(), ..SOption::default() },
+            SOption { title: "Group 2".into(), header: true, ..SOption::default() },
+            SOption { title: "Grape".into(), ..SOption::default() },
+            SOption { title: "Apricot".into(), ..SOption::default() },
+            SOption { title: "Papaya".into(), ..SOption::default() },
+        ];
+
+        let scored = score_by_group(&options, "ap".into(), score_unaccent());
+        assert_eq!(
+            scored.iter().map(|(i, option, positions)| (*i, option.title.as_str(), positions.clone())).collect::<Vec<_>>(),
+            vec![
+                (1, "Apple", vec![0, 1]),
+                (0, "Pineapple", vec![4, 5]),
+                (4, "Group 2", vec![]),
+                (6, "Apricot", vec![0, 1]),
+                (7, "Papaya", vec![1, 2]),
+                (5, "Grape", vec![2, 3]),
+            ]
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,98 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use yew::prelude::*;
+use super::SOption;
+
+#[cfg(doc)]
+use super::SearchableSelect;
+
+/// Future returned by a [LoaderFn]: the options matching the searched text, or an
+/// error message displayed in the list.
+pub type LoaderFuture<T> = Pin<Box<dyn Future<Output = Result<Vec<SOption<T>>, AttrValue>>>>;
+
+/// Type of the function inside [LoaderFn] structure.
+///
+/// It receives the searched text, which is empty when the select is opened.
+type LoaderFnInner<T> = dyn Fn(AttrValue) -> LoaderFuture<T>;
+
+/// # Options loader for [SearchableSelect]
+///
+/// Structure containing an asynchronous function loading the options matching the
+/// searched text, for example from a remote API. When the `loader` property of
+/// [SearchableSelect] is set, it replaces the filtering of `options` with a
+/// [FilterFn][super::FilterFn].
+///
+/// It can be created from any function returning a future:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{LoaderFn, SOption};
+///
+/// async fn search_users(search: AttrValue) -> Result<Vec<SOption>, AttrValue> {
+///     // Call the API here
+///     Ok(vec![SOption::new(search.clone(), search)])
+/// }
+///
+/// let loader = LoaderFn::from(search_users);
+/// ```
+pub struct LoaderFn<T = AttrValue> (
+    /// Internal function, inside [Rc] for cheap Clone implementation.
+    pub Rc<LoaderFnInner<T>>
+);
+
+impl<T> Clone for LoaderFn<T> {
+    fn clone(&self) -> Self {
+        LoaderFn(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for LoaderFn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T, F, Fut> From<F> for LoaderFn<T>
+where
+    F: Fn(AttrValue) -> Fut + 'static,
+    Fut: Future<Output = Result<Vec<SOption<T>>, AttrValue>> + 'static,
+{
+    fn from(f: F) -> Self {
+        LoaderFn(Rc::new(move |search| Box::pin(f(search))))
+    }
+}
+
+/// Options loaded by a [LoaderFn]
+#[derive(PartialEq)]
+pub (crate) struct LoadState<T> {
+    /// Options of the last successful request
+    pub options: Rc<Vec<SOption<T>>>,
+
+    /// A request is pending
+    pub loading: bool,
+
+    /// Error of the last request
+    pub error: Option<AttrValue>,
+}
+
+impl<T> Default for LoadState<T> {
+    fn default() -> Self {
+        LoadState {
+            options: Rc::new(Vec::new()),
+            loading: false,
+            error: None,
+        }
+    }
+}
+
+impl<T> Clone for LoadState<T> {
+    fn clone(&self) -> Self {
+        LoadState {
+            options: self.options.clone(),
+            loading: self.loading,
+            error: self.error.clone(),
+        }
+    }
+}
@@ -1,26 +1,41 @@
+use std::ops::Range;
+use std::rc::Rc;
+use gloo_timers::callback::Timeout;
+use yew::platform::spawn_local;
 use yew::prelude::*;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
-use web_sys::{Document, Node, ScrollBehavior, ScrollIntoViewOptions, ScrollLogicalPosition};
+use web_sys::{Document, Element, Node, ScrollBehavior, ScrollIntoViewOptions, ScrollLogicalPosition};
 
 use crate::component::form::FormControlValidation;
+use crate::component::{Badge, Spinner};
+use crate::util::Color;
 
 pub mod soption;
 pub mod filter;
+pub mod loader;
 
 pub use soption::*;
 pub use filter::*;
+pub use loader::*;
+
+/// Maximum height of the list of options, in pixels
+const LIST_HEIGHT: u32 = 200;
 
 /// # Properties for [SearchableSelect]
 ///
 /// See [SearchableSelect] component for the description of the component.
 #[derive(Properties, PartialEq)]
-pub struct SearchableSelectProps {
-    /// List of options to display
-    pub options: Vec<SOption>,
+pub struct SearchableSelectProps<T: Clone + PartialEq + 'static = AttrValue> {
+    /// List of options to display. When `loader` is set, the options are loaded
+    /// instead, and only the selected options of this list are used to mark the
+    /// loaded options as selected and to display the `chips`.
+    #[prop_or_default]
+    pub options: Vec<SOption<T>>,
 
     /// Title displayed in the select box. If None, placeholder is
-    /// displayed
+    /// displayed. Not used when `chips` is set.
+    #[prop_or_default]
     pub title: Option<AttrValue>,
 
     /// Placeholder for the select box when no option is selected
@@ -30,14 +45,35 @@ pub struct SearchableSelectProps {
     /// item is selected or deselected.
     ///
     /// Args:
-    /// - value (AttrValue): value of the option
+    /// - value (T, [AttrValue] by default): value of the option
     /// - selected (bool): indicate if option is going to be selected or not
-    pub onselectchange: Callback<(AttrValue, bool)>,
+    pub onselectchange: Callback<(T, bool)>,
 
     /// Callback to apply filter when search input is provided.
     /// If None, default filtering is used.
-    #[prop_or_else(filter_icase)]
-    pub filter: FilterFn,
+    #[prop_or_else(FilterFn::icase)]
+    pub filter: FilterFn<T>,
+
+    /// Function filtering and scoring the options, used instead of `filter` if set.
+    /// The options are sorted by relevance inside their group, and the matched
+    /// characters are highlighted.
+    #[prop_or_default]
+    pub score: Option<ScoreFn<T>>,
+
+    /// Asynchronous function loading the options matching the searched text, called
+    /// when the select is opened and when the search changes. `filter` is not used
+    /// in this case.
+    #[prop_or_default]
+    pub loader: Option<LoaderFn<T>>,
+
+    /// Delay in milliseconds after the last key press before calling the `loader`,
+    /// default 300
+    #[prop_or(300)]
+    pub debounce: u32,
+
+    /// Text read by screen readers while options are loading
+    #[prop_or(AttrValue::from("Loading..."))]
+    pub loading_text: AttrValue,
 
     /// Keep open when selection changes. This is used to permit multiple
     /// selections
@@ -68,6 +104,37 @@ pub struct SearchableSelectProps {
     /// Disable autoscroll when opening the component
     #[prop_or_default]
     pub noautoscroll: bool,
+
+    /// Only render the options visible in the list, plus `overscan` options before
+    /// and after them, for lists of thousands of options. All options, including
+    /// headers, are then displayed with the same `item_height`.
+    #[prop_or_default]
+    pub virtualize: bool,
+
+    /// Height of each option in pixels when `virtualize` is set, default 40
+    #[prop_or(40)]
+    pub item_height: u32,
+
+    /// Number of options rendered before and after the visible ones when
+    /// `virtualize` is set, default 5
+    #[prop_or(5)]
+    pub overscan: usize,
+
+    /// Called with the searched text when the user chooses to create a new option.
+    /// If set, an entry to create the option is displayed when the searched text
+    /// matches the title of no option.
+    #[prop_or_default]
+    pub on_create: Option<Callback<AttrValue>>,
+
+    /// Text of the entry creating an option, followed by the searched text, default
+    /// "Create"
+    #[prop_or(AttrValue::from("Create"))]
+    pub create_text: AttrValue,
+
+    /// Display the selected options as removable chips inside the select box, instead
+    /// of the `title`
+    #[prop_or_default]
+    pub chips: bool,
 }
 
 
@@ -160,6 +227,176 @@ pub struct SearchableSelectProps {
 /// }
 /// ```
 ///
+/// ## Typed values
+///
+/// Options can carry values of any type implementing `Clone` and `PartialEq`, for
+/// example ids or enums, which are then passed to `onselectchange` without having
+/// to parse strings. The type is given to the component, and defaults to
+/// [AttrValue]:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{SearchableSelect, SOption};
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Fruit { Apple, Banana }
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let selected = use_state(|| None::<Fruit>);
+///
+///     let options = vec![
+///         SOption { selected: *selected == Some(Fruit::Apple), ..SOption::new(Fruit::Apple, "Apple") },
+///         SOption { selected: *selected == Some(Fruit::Banana), ..SOption::new(Fruit::Banana, "Banana") },
+///     ];
+///
+///     let onselectchange = {
+///         let selected = selected.clone();
+///         Callback::from(move |(fruit, to_selected): (Fruit, bool)| {
+///             selected.set(to_selected.then_some(fruit))
+///         })
+///     };
+///
+///     html! {
+///         <SearchableSelect<Fruit>
+///             { options }
+///             title={ selected.map(|_| AttrValue::from("A fruit is selected")) }
+///             placeholder="Select a fruit..."
+///             { onselectchange }
+///         />
+///     }
+/// }
+/// ```
+///
+/// The filtering functions for typed values are [FilterFn::icase] and
+/// [FilterFn::case].
+///
+/// ## Loading options asynchronously
+///
+/// For long lists, for example from a remote API, options can be loaded by a
+/// [LoaderFn] instead of providing all of them in `options`. The loader is called
+/// with the searched text when the select is opened, and when the search changes
+/// after a `debounce` delay. A [Spinner][crate::component::Spinner] is displayed
+/// while loading, and an error message is displayed if the loader fails. Responses
+/// of requests for an outdated search are ignored.
+///
+/// When loading, `options` only needs to contain the selected options, so that the
+/// loaded ones are displayed as selected.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{LoaderFn, SearchableSelect, SOption};
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let selected = use_state(|| None::<u32>);
+///
+///     let loader = use_memo((), |_| LoaderFn::from(|search: AttrValue| async move {
+///         // Call the API here
+///         let ids: Result<Vec<u32>, AttrValue> = Ok(vec![1, 2, 3]);
+///         ids.map(|ids| ids.into_iter().map(|id| SOption::new(id, format!("{search} {id}"))).collect())
+///     }));
+///
+///     let onselectchange = {
+///         let selected = selected.clone();
+///         Callback::from(move |(id, to_selected): (u32, bool)| selected.set(to_selected.then_some(id)))
+///     };
+///
+///     html! {
+///         <SearchableSelect<u32>
+///             options={ selected.iter().map(|id| SOption { selected: true, ..SOption::new(*id, "") }).collect::<Vec<_>>() }
+///             title={ selected.map(|id| AttrValue::from(format!("User {id}"))) }
+///             placeholder="Search users..."
+///             loader={ (*loader).clone() }
+///             debounce={ 500 }
+///             { onselectchange }
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Large lists
+///
+/// Rendering thousands of options makes the component slow. With `virtualize`, only
+/// the options visible in the list are rendered, plus `overscan` options before and
+/// after them. Headers, keyboard navigation and scrolling to the selected option
+/// work as usual, but all options must have the same height, set with `item_height`
+/// in pixels. Titles longer than a line are cut.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{SearchableSelect, SOption};
+///
+/// fn test() -> Html {
+///     let options: Vec<SOption<u32>> = (0..10_000).map(|i| SOption::new(i, format!("Item {i}"))).collect();
+///
+///     html! {
+///         <SearchableSelect<u32>
+///             {options}
+///             title={ None::<AttrValue> }
+///             placeholder="Select an item..."
+///             onselectchange={ Callback::from(|(_id, _selected): (u32, bool)| ()) }
+///             virtualize={true}
+///             item_height={40}
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Creating options and tags
+///
+/// When `on_create` is set, typing a text matching the title of no option displays
+/// an entry to create it, which is active until another option is chosen with the
+/// arrow keys. Choosing it calls `on_create` with the text, and the new option has
+/// to be added to `options` by the caller.
+///
+/// With `chips`, the selected options are displayed as [Badge] components inside the
+/// select box, each with a button deselecting it. Combined with `on_create` and
+/// `keep_open`, this makes a tag input:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{SearchableSelect, SOption};
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let tags = use_state(|| vec![(AttrValue::from("rust"), true), (AttrValue::from("yew"), false)]);
+///
+///     let options = tags.iter().map(|(tag, selected)| {
+///         SOption { selected: *selected, ..SOption::new(tag.clone(), tag.clone()) }
+///     }).collect::<Vec<_>>();
+///
+///     let onselectchange = {
+///         let tags = tags.clone();
+///         Callback::from(move |(value, selected): (AttrValue, bool)| {
+///             tags.set(tags.iter().map(|(tag, was_selected)| {
+///                 (tag.clone(), if *tag == value { selected } else { *was_selected })
+///             }).collect())
+///         })
+///     };
+///
+///     let on_create = {
+///         let tags = tags.clone();
+///         Callback::from(move |tag: AttrValue| {
+///             let mut new_tags = (*tags).clone();
+///             new_tags.push((tag, true));
+///             tags.set(new_tags)
+///         })
+///     };
+///
+///     html! {
+///         <SearchableSelect
+///             {options}
+///             placeholder="Add tags..."
+///             {onselectchange}
+///             {on_create}
+///             chips={true}
+///             keep_open={true}
+///         />
+///     }
+/// }
+/// ```
+///
 /// ## Use in forms
 ///
 /// The Searchable Select component can be used in forms, and additional fields are available in
@@ -177,6 +414,10 @@ pub struct SearchableSelectProps {
 /// - Use case-sensitive filtering, using function [filter_case],
 /// - Create your own function returning a [FilterFn] structure.
 ///
+/// To sort the options by relevance and highlight the matched characters, set the
+/// `score` field instead, to a [ScoreFn] like [score_fuzzy], [score_word_prefix]
+/// or [score_unaccent].
+///
 /// ## CSS styling
 ///
 /// Some additional styling can be performed:
@@ -186,14 +427,16 @@ pub struct SearchableSelectProps {
 /// - Each element including header have class `list-group-item`
 /// - Items which are not headers have class `list-group-item-action`, possibly with `selected`, `active` or `disabled` class
 /// - Headers elements have class `header`
+/// - Matched characters are inside `mark` elements when `score` is set
+/// - The entry creating an option has class `create`
 ///
 /// ## Misc
 ///
 /// When opening, the component scrolls to the currently selected element. This can be disabled with
 /// `noautoscroll` if this behaviour is not wanted.
 #[function_component]
-pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
-    if props.label != "" && props.id == "" {
+pub fn SearchableSelect<T: Clone + PartialEq + 'static = AttrValue>(props: &SearchableSelectProps<T>) -> Html {
+    if !props.label.is_empty() && props.id.is_empty() {
         panic!("When a label is provided, an id is required");
     }
 
@@ -203,6 +446,10 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
     // State for the current search text
     let search_text = use_state(|| AttrValue::from(""));
 
+    // The entry creating an option is active instead of the option at active_index.
+    // It is set when the search changes, and cleared when moving with arrows.
+    let create_active = use_state_eq(|| false);
+
     // A ref to the outer container, to detect outside clicks
     let container_ref = use_node_ref();
 
@@ -212,6 +459,11 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
     // A ref to the active element to ensure it stays visible
     let active_ref = use_node_ref();
 
+    // A ref to the list of options and its scroll position, to render only the
+    // visible options when virtualized
+    let list_ref = use_node_ref();
+    let scroll_top = use_state_eq(|| 0_u32);
+
     // Index of the active element when dropbox is open. It is:
     // - set to first selected when dropdown opens, or 0 if none is selected
     // - 0 when search value changes
@@ -219,9 +471,76 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
     // It can be controlled by arrows.
     let active_index = use_state(|| 0);
 
-    let filtered_options = filter_by_group(
-        &props.options, (*search_text).clone(), props.filter.clone()
-    );
+    // Options loaded by the `loader`, if any
+    let load_state = use_state(LoadState::<T>::default);
+
+    // Latest loader, to avoid reloading each time a new one is provided
+    let loader = use_mut_ref(|| None::<LoaderFn<T>>);
+    *loader.borrow_mut() = props.loader.clone();
+
+    // Load options when the dropdown is open and the search changes, after the
+    // debounce delay. The timer is cancelled and the response of a pending request
+    // is ignored when the search changes again or when the dropdown closes.
+    {
+        let load_state = load_state.clone();
+        let debounce = props.debounce;
+        use_effect_with(
+            (*is_open && props.loader.is_some(), (*search_text).clone()),
+            move |(load, search)| {
+                let cancelled = Rc::new(std::cell::Cell::new(false));
+                let timer = load.then(|| {
+                    load_state.set(LoadState { loading: true, error: None, ..(*load_state).clone() });
+
+                    let cancelled = cancelled.clone();
+                    let search = search.clone();
+                    let delay = if search.is_empty() { 0 } else { debounce };
+                    Timeout::new(delay, move || {
+                        let Some(loader) = loader.borrow().clone() else {
+                            return;
+                        };
+                        let request = loader.0(search);
+                        spawn_local(async move {
+                            let result = request.await;
+                            if cancelled.get() {
+                                return;
+                            }
+                            load_state.set(match result {
+                                Ok(options) => LoadState { options: Rc::new(options), ..LoadState::default() },
+                                Err(error) => LoadState { error: Some(error), ..LoadState::default() },
+                            });
+                        });
+                    })
+                });
+
+                move || {
+                    cancelled.set(true);
+                    drop(timer);
+                }
+            }
+        );
+    }
+
+    // Loaded options are marked as selected like the options of the properties
+    let loaded_options = props.loader.as_ref().map(|_| {
+        load_state.options.iter().cloned().map(|mut option| {
+            option.selected |= props.options.iter().any(
+                |selected| selected.selected && selected.value == option.value
+            );
+            option
+        }).collect::<Vec<_>>()
+    });
+    let options = loaded_options.as_deref().unwrap_or(&props.options);
+
+    // Loaded options are already filtered by the loader. When scoring, the indices of
+    // the matched characters of each option are kept to highlight them.
+    let (filtered_options, highlights): (Vec<_>, Vec<_>) = match (&props.loader, &props.score) {
+        (Some(_), _) => (options.iter().enumerate().collect(), Vec::new()),
+        (None, Some(score)) => score_by_group(options, (*search_text).clone(), score.clone())
+            .into_iter()
+            .map(|(i, option, positions)| ((i, option), positions))
+            .unzip(),
+        (None, None) => (filter_by_group(options, (*search_text).clone(), props.filter.clone()), Vec::new()),
+    };
 
     // Specify active elements, previous and next depending on active_index, and only iterating
     // on elements which are not header and enabled.
@@ -230,6 +549,15 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
         next_active, current_active_index
     ) = get_actives(&filtered_options, *active_index);
 
+    // Entry creating an option from the search text, active when selected with arrows
+    // or when no option can be selected
+    let show_create = props.on_create.is_some()
+        && !(props.loader.is_some() && load_state.loading)
+        && can_create(options, &search_text);
+    let creating = show_create && (*create_active || current_active.is_none());
+    let current_active = if creating { None } else { current_active };
+    let current_active_index = if creating { None } else { current_active_index };
+
     // Toggle the dropdown open/closed
     let on_toggle_dropdown = {
         if props.disabled {
@@ -238,8 +566,12 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
             let is_open = is_open.clone();
             let active_index = active_index.clone();
             let search_text = search_text.clone();
+            let scroll_top = scroll_top.clone();
+            let create_active = create_active.clone();
 
-            let first_selected = props.options.iter().position(|option| option.selected);
+            // Loaded options are replaced when opening, so the first one is active
+            let first_selected = options.iter().position(|option| option.selected)
+                .filter(|_| props.loader.is_none());
 
             Callback::from(move |_| {
                 let new_open = !*is_open;
@@ -247,6 +579,8 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
                 if new_open {
                     active_index.set(first_selected.unwrap_or(0));
                     search_text.set(AttrValue::from(""));
+                    scroll_top.set(0);
+                    create_active.set(false);
                 }
             })
         }
@@ -259,7 +593,11 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
         let active_index = active_index.clone();
         let input_ref = input_ref.clone();
         let active_ref = active_ref.clone();
+        let list_ref = list_ref.clone();
+        let scroll_top = scroll_top.clone();
         let noautoscroll = props.noautoscroll;
+        let virtualize = props.virtualize;
+        let item_height = props.item_height;
         use_effect_with(
             (*is_open, *active_index),
             move |(is_open, _)| {
@@ -269,7 +607,16 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
                     }
 
                     // Make active element visible into the list container, only scrolling this container.
-                    if !noautoscroll {
+                    // When virtualized, the active element may not be rendered, so its position is
+                    // computed instead.
+                    if !noautoscroll && virtualize {
+                        if let (Some(index), Some(list)) = (current_active_index, list_ref.cast::<Element>()) {
+                            if let Some(top) = scroll_to_item(index, item_height, list.scroll_top().max(0) as u32) {
+                                list.set_scroll_top(top as i32);
+                                scroll_top.set(top);
+                            }
+                        }
+                    } else if !noautoscroll {
                         if let Some(element) = active_ref.cast::<web_sys::Element>() {
                             let scroll_options = ScrollIntoViewOptions::new();
                             scroll_options.set_block(ScrollLogicalPosition::Nearest);
@@ -286,27 +633,46 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
     let on_search_input = {
         let search_text = search_text.clone();
         let active_index = active_index.clone();
+        let create_active = create_active.clone();
         Callback::from(move |e: InputEvent| {
             // We read the entire value from the event's target
             if let Some(input_elem) = e.target_dyn_into::<web_sys::HtmlInputElement>() {
                 search_text.set(input_elem.value().into());
                 active_index.set(0);
+                create_active.set(true);
             }
         })
     };
 
+    // When the user chooses to create an option from the search text
+    let on_create = {
+        let is_open = is_open.clone();
+        let search_text = search_text.clone();
+        let on_create = props.on_create.clone();
+        let keep_open = props.keep_open;
+        Callback::from(move |_| {
+            if !keep_open {
+                is_open.set(false);
+            }
+            if let Some(on_create) = &on_create {
+                on_create.emit(AttrValue::from(search_text.trim().to_string()));
+            }
+            search_text.set(AttrValue::from(""));
+        })
+    };
+
     // Second handler to specifically detect special keys and move selection
     let on_key_press = {
         let active_index = active_index.clone();
+        let create_active = create_active.clone();
         let is_open = is_open.clone();
         let selectchange = props.onselectchange.clone();
+        let on_create = on_create.clone();
 
-        let (active_value, active_selected) = if let Some(index) = current_active_index {
+        let active = current_active_index.map(|index| {
             let (_, option) = filtered_options.get(index).unwrap();
-            (option.value.clone(), option.selected)
-        } else {
-            (AttrValue::from(""), false)
-        };
+            (option.value.clone(), !option.selected)
+        });
 
         let keep_open = props.keep_open;
 
@@ -314,18 +680,31 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
             let active_index = active_index.clone();
             match e.key().as_str() {
                 "Enter" => {
-                    if !keep_open {
-                        is_open.set(false);
+                    if creating {
+                        on_create.emit(());
+                    } else {
+                        if !keep_open {
+                            is_open.set(false);
+                        }
+                        if let Some(active) = active.clone() {
+                            selectchange.emit(active);
+                        }
                     }
-                    selectchange.emit((active_value.clone(), !active_selected));
                     e.prevent_default();
                 }
                 "ArrowUp" => {
-                    if let Some(index) = prev_active { active_index.set(index) };
+                    match prev_active {
+                        Some(index) if !creating => active_index.set(index),
+                        _ => create_active.set(show_create),
+                    }
                     e.prevent_default();
                 }
                 "ArrowDown" => {
-                    if let Some(index) = next_active { active_index.set(index) };
+                    if creating {
+                        create_active.set(false);
+                    } else if let Some(index) = next_active {
+                        active_index.set(index)
+                    };
                     e.prevent_default();
                 },
                 "Escape" => {
@@ -404,6 +783,23 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
         )
     };
 
+    // Options rendered in the list
+    let window = if props.virtualize {
+        visible_window(filtered_options.len(), props.item_height, *scroll_top, props.overscan)
+    } else {
+        0..filtered_options.len()
+    };
+
+    let onscroll = {
+        let scroll_top = scroll_top.clone();
+        let virtualize = props.virtualize;
+        Callback::from(move |event: Event| {
+            if let Some(list) = virtualize.then(|| event.target_dyn_into::<Element>()).flatten() {
+                scroll_top.set(list.scroll_top().max(0) as u32);
+            }
+        })
+    };
+
     let (validation, validation_class) = match props.validation.clone() {
         FormControlValidation::None => (None, None),
         FormControlValidation::Valid(None) => (None, Some("is-valid")),
@@ -415,8 +811,45 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
         }), Some("is-invalid")),
     };
 
-    let label = (props.label != "").then_some(html! {
-        <label for={ props.id.clone() } class={ "form-label" }>{ props.label.clone() }</label>
+    // Selected options displayed as chips, removed by deselecting them. With a loader,
+    // the options of the properties are the selected ones, loaded or not.
+    let chips = props.chips.then(|| {
+        props.options.iter().filter(|option| option.selected && !option.header).map(|option| {
+            let onclick = {
+                let value = option.value.clone();
+                let selectchange = props.onselectchange.clone();
+                Callback::from(move |e: MouseEvent| {
+                    // Do not toggle the dropdown
+                    e.stop_propagation();
+                    selectchange.emit((value.clone(), false));
+                })
+            };
+            html! {
+                <Badge class="d-inline-flex align-items-center" style={Color::Secondary}>
+                    { option.title.clone() }
+                    <button
+                        type="button"
+                        class="btn-close btn-close-white ms-1"
+                        style="font-size: 0.6em;"
+                        aria-label={format!("Remove {}", option.title)}
+                        disabled={props.disabled}
+                        {onclick}
+                    />
+                </Badge>
+            }
+        }).collect::<Vec<Html>>()
+    });
+
+    // A label only targets form controls: the chips are a group referring to it
+    let label_id = format!("{}-label", props.id);
+    let label = (!props.label.is_empty()).then_some(html! {
+        <label
+            id={ label_id.clone() }
+            for={ (!props.chips).then(|| props.id.clone()) }
+            class={ "form-label" }
+        >
+            { props.label.clone() }
+        </label>
     });
 
     html! {
@@ -424,16 +857,32 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
             // Select input and toggle button
             { label }
             <div class="input-group" onclick={on_toggle_dropdown.clone()}>
-                // Readonly input to show current selection
-                <input
-                    id={ props.id.clone() }
-                    type="text"
-                    class={ classes!("form-control", validation_class) }
-                    value={ props.title.clone().unwrap_or("".into()) }
-                    placeholder={props.placeholder.clone()}
-                    disabled={ props.disabled }
-                    readonly={true}
-                />
+                if let Some(chips) = chips {
+                    // Chips of the selected options
+                    <div
+                        id={ props.id.clone() }
+                        role="group"
+                        aria-labelledby={ (!props.label.is_empty()).then_some(label_id) }
+                        class={ classes!("form-control", "d-flex", "flex-wrap", "gap-1", validation_class) }
+                    >
+                        if chips.is_empty() {
+                            <span class="text-muted">{ props.placeholder.clone() }</span>
+                        } else {
+                            { for chips }
+                        }
+                    </div>
+                } else {
+                    // Readonly input to show current selection
+                    <input
+                        id={ props.id.clone() }
+                        type="text"
+                        class={ classes!("form-control", validation_class) }
+                        value={ props.title.clone().unwrap_or("".into()) }
+                        placeholder={props.placeholder.clone()}
+                        disabled={ props.disabled }
+                        readonly={true}
+                    />
+                }
                 // Toggle button
                 <button
                     class="btn btn-outline-secondary dropdown-toggle"
@@ -462,27 +911,68 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
                             type="text"
                             class="form-control"
                             placeholder="Search..."
+                            value={(*search_text).clone()}
                             oninput={on_search_input}
                             onkeydown={on_key_press}
                         />
                     </div>
 
+                    // Loading state and error
+                    if props.loader.is_some() && load_state.loading {
+                        <div class="list-group list-group-flush">
+                            <div class="list-group-item text-center">
+                                <Spinner small={true}>{ props.loading_text.clone() }</Spinner>
+                            </div>
+                        </div>
+                    }
+                    if let Some(error) = &load_state.error {
+                        <div class="list-group list-group-flush">
+                            <div class="list-group-item text-danger">{ error.clone() }</div>
+                        </div>
+                    }
+
+                    // Entry creating an option
+                    if show_create {
+                        <div class="list-group list-group-flush">
+                            <a
+                                class={ classes!("list-group-item", "list-group-item-action", "create", creating.then_some("active")) }
+                                style="padding-left: 25px"
+                                onclick={on_create.reform(|_| ())}
+                            >
+                                { format!("{} \"{}\"", props.create_text, search_text.trim()) }
+                            </a>
+                        </div>
+                    }
+
                     // List of filtered options
-                    <div class="list-group list-group-flush" style="max-height: 200px; overflow-y: auto;">
+                    <div
+                        ref={list_ref}
+                        class="list-group list-group-flush"
+                        style={format!("max-height: {LIST_HEIGHT}px; overflow-y: auto;")}
+                        {onscroll}
+                    >
+                    if props.virtualize {
+                        <div style={format!("height: {}px; flex-shrink: 0;", window.start as u32 * props.item_height)} />
+                    }
                     {
-                        filtered_options.iter().map(|(i, option)| {
+                        filtered_options[window.clone()].iter().zip(window.clone()).map(|((i, option), position)| {
                             let active = Some(*i) == current_active;
                             html!{
-                                <SOptionComp
+                                <SOptionComp<T>
                                     key={*i}
                                     node_ref={if active { Some(active_ref.clone()) } else { None }}
                                     attrs={(*option).clone()}
                                     onselectchange={onselectchange.clone()}
                                     {active}
+                                    height={props.virtualize.then_some(props.item_height)}
+                                    highlight={highlights.get(position).cloned().unwrap_or_default()}
                                 />
                             }
                         }).collect::<Html>()
                     }
+                    if props.virtualize {
+                        <div style={format!("height: {}px; flex-shrink: 0;", (filtered_options.len() - window.end) as u32 * props.item_height)} />
+                    }
                     </div>
                 </div>
             }
@@ -490,26 +980,65 @@ pub fn SearchableSelect(props: &SearchableSelectProps) -> Html {
     }
 }
 
+/// Whether an option can be created from the searched text: it is not blank and
+/// matches the title of no option, ignoring case and surrounding spaces
+fn can_create<T>(options: &[SOption<T>], search: &str) -> bool {
+    let search = search.trim().to_lowercase();
+    !search.is_empty() && !options.iter().any(
+        |option| !option.header && option.title.trim().to_lowercase() == search
+    )
+}
+
+/// Range of the options to render in a virtualized list of `count` options of
+/// `item_height` pixels scrolled by `scroll_top` pixels, with `overscan` additional
+/// options before and after the visible ones
+fn visible_window(count: usize, item_height: u32, scroll_top: u32, overscan: usize) -> Range<usize> {
+    let item_height = item_height.max(1);
+    let first = ((scroll_top / item_height) as usize).min(count);
+    let visible = LIST_HEIGHT.div_ceil(item_height) as usize + 1;
+    first.saturating_sub(overscan)..(first + visible + overscan).min(count)
+}
+
+/// Scroll position of a virtualized list of options of `item_height` pixels
+/// scrolled by `scroll_top` pixels, so that the option at `index` is visible. The
+/// list is scrolled as little as possible, and `None` is returned if the option is
+/// already visible.
+fn scroll_to_item(index: usize, item_height: u32, scroll_top: u32) -> Option<u32> {
+    let top = index as u32 * item_height;
+    let bottom = top + item_height;
+    if top < scroll_top {
+        Some(top)
+    } else if bottom > scroll_top + LIST_HEIGHT {
+        Some(bottom - LIST_HEIGHT)
+    } else {
+        None
+    }
+}
+
 /// Find current active, previous and next, and return filtered index
 ///
 /// options is the result of [super::filter_by_group] and is a vector of `(usize, &SOption)`. This is
 /// the list after filtering, but the index (usize) corresponds to the original index before filtering.
 ///
-/// `current_index` corresponds to such index (pre-filter).
+/// `current_index` corresponds to such index (pre-filter). The list may be sorted
+/// differently than the pre-filter indexes, when options are sorted by score: the
+/// option with this index is used if it can be active, otherwise the first one after
+/// it.
 ///
 /// prev: the index (pre-filter) of active element after pressing KeyUp
 /// current: the one currently active
 /// next: the index (pre-filter) of active element after pressing KeyDown
 /// current_index: the index of "current" in the filtered list, independently of the pre-filter index
 /// in the element.
-fn get_actives(options: &Vec<(usize, &SOption)>, current_index: usize)
+fn get_actives<T>(options: &Vec<(usize, &SOption<T>)>, current_index: usize)
     -> (Option<usize>, Option<usize>, Option<usize>, Option<usize>)
 {
     let iter = options.iter().filter(
         |(_, option)| !option.disabled && !option.header
     );
 
-    let index = iter.clone().position(|(i, _)| *i >= current_index);
+    let index = iter.clone().position(|(i, _)| *i == current_index)
+        .or_else(|| iter.clone().position(|(i, _)| *i >= current_index));
 
     let indexes: Vec<usize> = iter.map(|(i, _)| *i).collect();
 
@@ -525,15 +1054,53 @@ fn get_actives(options: &Vec<(usize, &SOption)>, current_index: usize)
 mod test {
     use super::*;
 
+    #[test]
+    pub fn test_can_create() {
+        let options: Vec<SOption> = vec![
+            SOption { title: "Fruits".into(), header: true, ..SOption::default() },
+            SOption { title: "Apple".into(), ..SOption::default() },
+            SOption { title: "Banana".into(), disabled: true, ..SOption::default() },
+        ];
+
+        assert!(!can_create(&options, ""));
+        assert!(!can_create(&options, "   "));
+        assert!(!can_create(&options, "apple"));
+        assert!(!can_create(&options, " Banana "));
+        assert!(can_create(&options, "App"));
+        assert!(can_create(&options, "Fruits"));
+        assert!(can_create::<AttrValue>(&[], "Cherry"));
+    }
+
+    #[test]
+    pub fn test_visible_window() {
+        // 200px list of 40px items: 5 items are visible, 6 when scrolled
+        assert_eq!(visible_window(0, 40, 0, 5), 0..0);
+        assert_eq!(visible_window(3, 40, 0, 5), 0..3);
+        assert_eq!(visible_window(1000, 40, 0, 5), 0..11);
+        assert_eq!(visible_window(1000, 40, 4000, 5), 95..111);
+        assert_eq!(visible_window(1000, 40, 39_990, 5), 994..1000);
+        assert_eq!(visible_window(10, 40, 4000, 2), 8..10);
+    }
+
+    #[test]
+    pub fn test_scroll_to_item() {
+        assert_eq!(scroll_to_item(0, 40, 0), None);
+        assert_eq!(scroll_to_item(4, 40, 0), None);
+        assert_eq!(scroll_to_item(5, 40, 0), Some(40));
+        assert_eq!(scroll_to_item(100, 40, 0), Some(3840));
+        assert_eq!(scroll_to_item(2, 40, 400), Some(80));
+        assert_eq!(scroll_to_item(11, 40, 400), None);
+    }
+
     #[test]
     pub fn test_get_actives() {
         assert_eq!(
-            get_actives(&vec![], 0),
+            get_actives::<AttrValue>(&vec![], 0),
             (None, None, None, None)
         );
 
         assert_eq!(
-            get_actives(&vec![], 1),
+            get_actives::<AttrValue>(&vec![], 1),
             (None, None, None, None)
         );
 
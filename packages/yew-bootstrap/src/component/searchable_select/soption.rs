@@ -10,11 +10,13 @@ const CHECKMARK: &str =
 
 /// # SOption element
 ///
-/// This is included in the list of options for a [crate::component::SearchableSelect]
+/// This is included in the list of options for a [crate::component::SearchableSelect].
+/// The value is an [AttrValue] by default, but can be of any type `T`, which is then
+/// passed to the `onselectchange` callback of the select.
 #[derive(PartialEq, Clone)]
-pub struct SOption {
-    /// value of the option as AttrValue
-    pub value: AttrValue,
+pub struct SOption<T = AttrValue> {
+    /// value of the option, [AttrValue] by default
+    pub value: T,
 
     /// Title of the option, ie what is displayed
     pub title: AttrValue,
@@ -32,10 +34,10 @@ pub struct SOption {
     pub classes: Classes,
 }
 
-impl Default for SOption {
+impl<T: Default> Default for SOption<T> {
     fn default() -> Self {
         SOption {
-            value: AttrValue::from(""),
+            value: T::default(),
             title: AttrValue::from(""),
             selected: false,
             header: false,
@@ -45,14 +47,29 @@ impl Default for SOption {
     }
 }
 
+impl<T> SOption<T> {
+    /// Option with the given value and title, useful when `T` has no [Default]
+    /// value
+    pub fn new(value: T, title: impl Into<AttrValue>) -> Self {
+        SOption {
+            value,
+            title: title.into(),
+            selected: false,
+            header: false,
+            disabled: false,
+            classes: Classes::default()
+        }
+    }
+}
+
 /// # Properties for [SOptionComp] component.
 ///
 /// This should not be used directly, provide vector of [SOption] instead
 /// to [crate::SearchableSelect]
 #[derive(Properties, PartialEq)]
-pub (crate) struct SOptionProps {
+pub (crate) struct SOptionProps<T: Clone + PartialEq + 'static> {
     /// Properties derived from SOption
-    pub attrs: SOption,
+    pub attrs: SOption<T>,
 
     /// Element is active
     #[prop_or_default]
@@ -62,14 +79,36 @@ pub (crate) struct SOptionProps {
     pub node_ref: Option<NodeRef>,
 
     /// Called when the user clicks on the item with new value as parameter.
-    pub onselectchange: Callback<(AttrValue, bool)>,
+    pub onselectchange: Callback<(T, bool)>,
+
+    /// Fixed height of the item in pixels, used by virtualized lists
+    #[prop_or_default]
+    pub height: Option<u32>,
+
+    /// Indices of the characters of the title to highlight
+    #[prop_or_default]
+    pub highlight: Vec<usize>,
+}
+
+/// Split `title` into consecutive parts, which are either highlighted or not
+/// depending on whether the index of their characters is in `positions`
+fn highlight_segments(title: &str, positions: &[usize]) -> Vec<(String, bool)> {
+    let mut segments: Vec<(String, bool)> = Vec::new();
+    for (index, c) in title.chars().enumerate() {
+        let highlighted = positions.contains(&index);
+        match segments.last_mut() {
+            Some((segment, last)) if *last == highlighted => segment.push(c),
+            _ => segments.push((c.to_string(), highlighted)),
+        }
+    }
+    segments
 }
 
 /// # Option for [crate::SearchableSelect]
 ///
 /// Display one option inside the select block.
 #[function_component]
-pub (crate) fn SOptionComp(props: &SOptionProps) -> Html {
+pub (crate) fn SOptionComp<T: Clone + PartialEq + 'static>(props: &SOptionProps<T>) -> Html {
     let disabled = props.attrs.disabled && !props.attrs.header;
     let active = props.active && !props.attrs.header;
 
@@ -101,24 +140,57 @@ pub (crate) fn SOptionComp(props: &SOptionProps) -> Html {
         "".to_string()
     };
 
+    let height = props.height.map(|height| format!(";height: {height}px;overflow: hidden")).unwrap_or_default();
+
     let style = format!(
-        "padding-left: 25px{}", selected
+        "padding-left: 25px{}{}", selected, height
     );
 
+    let title = if props.highlight.is_empty() {
+        html! { props.attrs.title.clone() }
+    } else {
+        highlight_segments(&props.attrs.title, &props.highlight).into_iter().map(|(segment, highlighted)| {
+            if highlighted {
+                html! { <mark class="p-0">{ segment }</mark> }
+            } else {
+                html! { segment }
+            }
+        }).collect::<Html>()
+    };
+
     if props.attrs.header {
         html! {
-            <li {class}>
+            <li {class} style={height.strip_prefix(';').map(str::to_string)}>
                 <h6>{ props.attrs.title.clone() }</h6>
             </li>
         }
     } else {
         html! {
             if let Some(node_ref) = props.node_ref.clone() {
-                <a {class} {onclick} ref={node_ref} style={style}>{ props.attrs.title.clone() }</a>
+                <a {class} {onclick} ref={node_ref} style={style}>{ title }</a>
             } else {
-                <a {class} {onclick} style={style}>{ props.attrs.title.clone() }</a>
+                <a {class} {onclick} style={style}>{ title }</a>
             }
         }
     }
 }
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_highlight_segments() {
+        assert_eq!(highlight_segments("", &[0]), vec![]);
+        assert_eq!(highlight_segments("Apple", &[]), vec![("Apple".to_string(), false)]);
+        assert_eq!(
+            highlight_segments("Crème brûlée", &[0, 1, 2, 9, 10]),
+            vec![
+                ("Crè".to_string(), true),
+                ("me brû".to_string(), false),
+                ("lé".to_string(), true),
+                ("e".to_string(), false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,559 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+use std::rc::Rc;
+
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use super::{ContainerSize, Pagination};
+
+/// Comparison of two rows, see [TableColumn::sort_by]
+type Compare<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+/// Direction in which a [Table] is sorted
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Smallest values first
+    #[default]
+    Ascending,
+    /// Largest values first
+    Descending,
+}
+
+impl SortDirection {
+    /// The opposite direction
+    pub fn reversed(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+}
+
+/// Sorting of a [Table]: index of the column in `columns`, and direction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableSort {
+    /// Index of the sorted column
+    pub column: usize,
+    /// Direction of the sort
+    pub direction: SortDirection,
+}
+
+/// # Column of a [Table]
+///
+/// Defines the header of the column, how to render its cell for a row, and
+/// optionally how to sort rows by this column.
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::TableColumn;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct User { name: String, age: u32 }
+///
+/// let column = TableColumn::new("Age", |user: &User| html! { user.age })
+///     .sort_by_key(|user| user.age)
+///     .class("text-end");
+/// ```
+pub struct TableColumn<T> {
+    header: AttrValue,
+    cell: Rc<dyn Fn(&T) -> Html>,
+    compare: Option<Compare<T>>,
+    class: Classes,
+}
+
+impl<T: 'static> TableColumn<T> {
+    /// Column titled `header`, whose cells are rendered by `cell`
+    pub fn new(header: impl Into<AttrValue>, cell: impl Fn(&T) -> Html + 'static) -> Self {
+        Self {
+            header: header.into(),
+            cell: Rc::new(cell),
+            compare: None,
+            class: Classes::new(),
+        }
+    }
+
+    /// Make the column sortable, comparing rows by the value returned by `key`
+    pub fn sort_by_key<K: Ord>(self, key: impl Fn(&T) -> K + 'static) -> Self {
+        self.sort_by(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Make the column sortable, comparing rows with `compare`
+    pub fn sort_by(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Extra CSS classes of the header and cells of the column
+    pub fn class(mut self, class: impl Into<Classes>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<T> Clone for TableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            cell: self.cell.clone(),
+            compare: self.compare.clone(),
+            class: self.class.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for TableColumn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header
+            && self.class == other.class
+            && Rc::ptr_eq(&self.cell, &other.cell)
+            && match (&self.compare, &other.compare) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+/// Indices of `rows`, in the order given by `compare` and `direction`. The sort is
+/// stable, and rows are kept in their order without `compare`.
+fn sorted_indices<T>(rows: &[T], compare: Option<&Compare<T>>, direction: SortDirection) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..rows.len()).collect();
+    if let Some(compare) = compare {
+        indices.sort_by(|a, b| {
+            let ordering = compare(&rows[*a], &rows[*b]);
+            match direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        });
+    }
+    indices
+}
+
+/// Number of pages of `len` rows, at least one
+fn page_count(len: usize, page_size: usize) -> usize {
+    len.div_ceil(page_size.max(1)).max(1)
+}
+
+/// Range of the rows displayed on `page` (1-based), clamped to the existing pages
+fn page_range(len: usize, page_size: usize, page: usize) -> Range<usize> {
+    let page_size = page_size.max(1);
+    let page = page.clamp(1, page_count(len, page_size));
+    let start = (page - 1) * page_size;
+    start..(start + page_size).min(len)
+}
+
+/// Selected indices which still refer to one of the `len` rows
+fn selection_in_range(selected: &[usize], len: usize) -> Vec<usize> {
+    selected.iter().copied().filter(|index| *index < len).collect()
+}
+
+/// Selection after toggling the row `index`
+fn toggle_selection(selected: &[usize], index: usize) -> Vec<usize> {
+    if selected.contains(&index) {
+        selected.iter().copied().filter(|i| *i != index).collect()
+    } else {
+        let mut selected = selected.to_vec();
+        selected.push(index);
+        selected.sort_unstable();
+        selected
+    }
+}
+
+/// # Properties of [Table]
+#[derive(Properties, PartialEq)]
+pub struct TableProps<T: PartialEq + 'static> {
+    /// Rows of data, one line is displayed for each of them
+    pub rows: Vec<T>,
+
+    /// Columns, rendering the cells of each row
+    pub columns: Vec<TableColumn<T>>,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Extra CSS classes of the `thead` element, for example `table-light`
+    #[prop_or_default]
+    pub head_class: Classes,
+
+    /// Optional caption of the table
+    #[prop_or_default]
+    pub caption: Option<AttrValue>,
+
+    /// Zebra-striped rows
+    #[prop_or_default]
+    pub striped: bool,
+
+    /// Highlight the row below the mouse cursor
+    #[prop_or_default]
+    pub hover: bool,
+
+    /// Borders on all sides of the table and cells
+    #[prop_or_default]
+    pub bordered: bool,
+
+    /// No borders
+    #[prop_or_default]
+    pub borderless: bool,
+
+    /// Compact table, with smaller cell padding
+    #[prop_or_default]
+    pub small: bool,
+
+    /// Scroll the table horizontally on screens smaller than this size, or on all
+    /// screens with [ContainerSize::ExtraSmall]
+    #[prop_or_default]
+    pub responsive: Option<ContainerSize>,
+
+    /// Keep the header visible when scrolling. The `head_class` should set a
+    /// background, like `table-light`.
+    #[prop_or_default]
+    pub sticky_header: bool,
+
+    /// Current sorting. When set, the sorting is controlled: the application must
+    /// update it from `on_sort`. When unset, the table manages it itself, starting
+    /// with the rows in their original order.
+    #[prop_or_default]
+    pub sort: Option<TableSort>,
+
+    /// Called when a sortable header is clicked, with the requested sorting
+    #[prop_or_default]
+    pub on_sort: Callback<TableSort>,
+
+    /// Display a checkbox to select each row, and all rows in the header
+    #[prop_or_default]
+    pub selectable: bool,
+
+    /// Indices in `rows` of the selected rows. When set, the selection is
+    /// controlled: the application must update it from `on_select`.
+    #[prop_or_default]
+    pub selected: Option<Vec<usize>>,
+
+    /// Called with the indices in `rows` of the selected rows, when the selection
+    /// changes
+    #[prop_or_default]
+    pub on_select: Callback<Vec<usize>>,
+
+    /// Called with the row which is clicked
+    #[prop_or_default]
+    pub on_row_click: Callback<T>,
+
+    /// Number of rows per page. All rows are displayed if unset, otherwise a
+    /// [Pagination] is displayed below the table.
+    #[prop_or_default]
+    pub page_size: Option<usize>,
+
+    /// Current page, 1-based. When set, the page is controlled: the application must
+    /// update it from `on_page_change`.
+    #[prop_or_default]
+    pub page: Option<usize>,
+
+    /// Called with the requested page, 1-based
+    #[prop_or_default]
+    pub on_page_change: Callback<usize>,
+}
+
+/// # Table component
+/// Table of data, rendering a line for each row with [TableColumn] definitions.
+///
+/// Sorting, selection and pagination are managed by the table, or can be
+/// controlled by the application with `sort`, `selected` and `page`. Rows are
+/// identified by their index in `rows` in selection callbacks, and passed by value
+/// to `on_row_click`.
+///
+/// See [TableProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Table, TableColumn};
+///
+/// #[derive(Clone, PartialEq)]
+/// struct User { name: String, age: u32 }
+///
+/// #[function_component]
+/// fn Users() -> Html {
+///     let users = vec![
+///         User { name: "Alice".into(), age: 34 },
+///         User { name: "Bob".into(), age: 27 },
+///     ];
+///     let columns = vec![
+///         TableColumn::new("Name", |user: &User| html! { user.name.clone() })
+///             .sort_by_key(|user| user.name.clone()),
+///         TableColumn::new("Age", |user: &User| html! { user.age })
+///             .sort_by_key(|user| user.age),
+///     ];
+///     let on_row_click = Callback::from(|user: User| gloo_console::log!(user.name));
+///
+///     html! {
+///         <Table<User> rows={users} {columns} striped={true} hover={true} selectable={true}
+///             {on_row_click} page_size={10} />
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Table<T>(props: &TableProps<T>) -> Html
+where
+    T: Clone + PartialEq + 'static,
+{
+    let uncontrolled_sort = use_state_eq(|| None::<TableSort>);
+    let uncontrolled_selected = use_state_eq(Vec::<usize>::new);
+    let uncontrolled_page = use_state_eq(|| 1_usize);
+    let select_all_ref = use_node_ref();
+
+    let sort = props.sort.or(*uncontrolled_sort);
+    let selected = props.selected.as_deref().unwrap_or(&uncontrolled_selected);
+    let selected = selection_in_range(selected, props.rows.len());
+    {
+        // Rows removed from `rows` are no longer selected
+        let uncontrolled_selected = uncontrolled_selected.clone();
+        use_effect_with(props.rows.len(), move |len| {
+            uncontrolled_selected.set(selection_in_range(&uncontrolled_selected, *len));
+        });
+    }
+
+    let compare = sort.and_then(|sort| props.columns.get(sort.column)).and_then(|column| column.compare.as_ref());
+    let order = sorted_indices(&props.rows, compare, sort.map(|sort| sort.direction).unwrap_or_default());
+
+    let (page, visible) = match props.page_size {
+        Some(page_size) => {
+            let page = props.page.unwrap_or(*uncontrolled_page).clamp(1, page_count(props.rows.len(), page_size));
+            (page, &order[page_range(props.rows.len(), page_size, page)])
+        }
+        None => (1, &order[..]),
+    };
+
+    let set_selected = {
+        let uncontrolled_selected = uncontrolled_selected.clone();
+        let on_select = props.on_select.clone();
+        let controlled = props.selected.is_some();
+        Callback::from(move |selected: Vec<usize>| {
+            if !controlled {
+                uncontrolled_selected.set(selected.clone());
+            }
+            on_select.emit(selected);
+        })
+    };
+
+    let on_page_change = {
+        let uncontrolled_page = uncontrolled_page.clone();
+        let on_page_change = props.on_page_change.clone();
+        let controlled = props.page.is_some();
+        Callback::from(move |page: usize| {
+            if !controlled {
+                uncontrolled_page.set(page);
+            }
+            on_page_change.emit(page);
+        })
+    };
+
+    // The "select all" checkbox is indeterminate when some rows are selected
+    let all_selected = !props.rows.is_empty() && selected.len() == props.rows.len();
+    let indeterminate = !selected.is_empty() && !all_selected;
+    {
+        let select_all_ref = select_all_ref.clone();
+        use_effect_with(indeterminate, move |indeterminate| {
+            if let Some(input) = select_all_ref.cast::<HtmlInputElement>() {
+                input.set_indeterminate(*indeterminate);
+            }
+        });
+    }
+
+    let select_all = {
+        let set_selected = set_selected.clone();
+        let len = props.rows.len();
+        Callback::from(move |_: MouseEvent| {
+            set_selected.emit(if all_selected { Vec::new() } else { (0..len).collect() });
+        })
+    };
+
+    let headers = props.columns.iter().enumerate().map(|(index, column)| {
+        let direction = sort.filter(|sort| sort.column == index).map(|sort| sort.direction);
+        let aria_sort = column.compare.as_ref().map(|_| match direction {
+            Some(SortDirection::Ascending) => "ascending",
+            Some(SortDirection::Descending) => "descending",
+            None => "none",
+        });
+
+        let header = if column.compare.is_some() {
+            let onclick = {
+                let uncontrolled_sort = uncontrolled_sort.clone();
+                let on_sort = props.on_sort.clone();
+                let controlled = props.sort.is_some();
+                Callback::from(move |_: MouseEvent| {
+                    let sort = TableSort {
+                        column: index,
+                        direction: direction.map(SortDirection::reversed).unwrap_or_default(),
+                    };
+                    if !controlled {
+                        uncontrolled_sort.set(Some(sort));
+                    }
+                    on_sort.emit(sort);
+                })
+            };
+            html! {
+                <button type="button" class="btn btn-link p-0 fw-bold text-reset text-decoration-none" {onclick}>
+                    { column.header.clone() }
+                    <span class="ms-1" aria-hidden="true">
+                        {
+                            match direction {
+                                Some(SortDirection::Ascending) => "▲",
+                                Some(SortDirection::Descending) => "▼",
+                                None => "↕",
+                            }
+                        }
+                    </span>
+                </button>
+            }
+        } else {
+            html! { column.header.clone() }
+        };
+
+        html! {
+            <th scope="col" class={column.class.clone()} aria-sort={aria_sort}>{ header }</th>
+        }
+    });
+
+    let rows = visible.iter().map(|index| {
+        let index = *index;
+        let row = &props.rows[index];
+        let is_selected = selected.contains(&index);
+
+        let onclick = {
+            let on_row_click = props.on_row_click.clone();
+            let row = row.clone();
+            Callback::from(move |_: MouseEvent| on_row_click.emit(row.clone()))
+        };
+        let toggle = {
+            let set_selected = set_selected.clone();
+            let selected = selected.clone();
+            Callback::from(move |event: MouseEvent| {
+                // Selecting a row is not a click on the row
+                event.stop_propagation();
+                set_selected.emit(toggle_selection(&selected, index));
+            })
+        };
+
+        html! {
+            <tr class={is_selected.then_some("table-active")} {onclick}>
+                if props.selectable {
+                    <td>
+                        <input class="form-check-input" type="checkbox" checked={is_selected} aria-label="Select row" onclick={toggle} />
+                    </td>
+                }
+                {
+                    for props.columns.iter().map(|column| html! {
+                        <td class={column.class.clone()}>{ (column.cell)(row) }</td>
+                    })
+                }
+            </tr>
+        }
+    });
+
+    let mut classes = classes!("table");
+    if props.striped {
+        classes.push("table-striped");
+    }
+    if props.hover {
+        classes.push("table-hover");
+    }
+    if props.bordered {
+        classes.push("table-bordered");
+    }
+    if props.borderless {
+        classes.push("table-borderless");
+    }
+    if props.small {
+        classes.push("table-sm");
+    }
+    classes.push(props.class.clone());
+
+    let mut head_classes = props.head_class.clone();
+    if props.sticky_header {
+        head_classes.push("sticky-top");
+    }
+
+    let table = html! {
+        <table class={classes}>
+            if let Some(caption) = &props.caption {
+                <caption>{ caption.clone() }</caption>
+            }
+            <thead class={head_classes}>
+                <tr>
+                    if props.selectable {
+                        <th scope="col">
+                            <input
+                                ref={select_all_ref}
+                                class="form-check-input"
+                                type="checkbox"
+                                checked={all_selected}
+                                aria-label="Select all rows"
+                                onclick={select_all}
+                            />
+                        </th>
+                    }
+                    { for headers }
+                </tr>
+            </thead>
+            <tbody>
+                { for rows }
+            </tbody>
+        </table>
+    };
+
+    let table = match &props.responsive {
+        Some(ContainerSize::ExtraSmall) => html! { <div class="table-responsive">{ table }</div> },
+        Some(size) => html! { <div class={format!("table-responsive-{}", size.to_string())}>{ table }</div> },
+        None => table,
+    };
+
+    match props.page_size {
+        Some(page_size) if props.rows.len() > page_size => html! {
+            <>
+                { table }
+                <Pagination total_pages={page_count(props.rows.len(), page_size)} current={page} {on_page_change} />
+            </>
+        },
+        _ => table,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_indices_is_stable() {
+        let rows = [3, 1, 2, 1];
+        let compare: Compare<i32> = Rc::new(|a, b| a.cmp(b));
+        assert_eq!(sorted_indices(&rows, None, SortDirection::Ascending), vec![0, 1, 2, 3]);
+        assert_eq!(sorted_indices(&rows, Some(&compare), SortDirection::Ascending), vec![1, 3, 2, 0]);
+        assert_eq!(sorted_indices(&rows, Some(&compare), SortDirection::Descending), vec![0, 2, 1, 3]);
+    }
+
+    #[test]
+    fn page_range_is_clamped() {
+        assert_eq!(page_count(0, 10), 1);
+        assert_eq!(page_count(25, 10), 3);
+        assert_eq!(page_range(25, 10, 1), 0..10);
+        assert_eq!(page_range(25, 10, 3), 20..25);
+        assert_eq!(page_range(25, 10, 9), 20..25);
+        assert_eq!(page_range(0, 10, 1), 0..0);
+    }
+
+    #[test]
+    fn toggle_selection_keeps_indices_sorted() {
+        assert_eq!(toggle_selection(&[], 2), vec![2]);
+        assert_eq!(toggle_selection(&[1, 3], 2), vec![1, 2, 3]);
+        assert_eq!(toggle_selection(&[1, 2, 3], 2), vec![1, 3]);
+    }
+
+    #[test]
+    fn selection_in_range_drops_removed_rows() {
+        assert_eq!(selection_in_range(&[3, 4], 3), Vec::<usize>::new());
+        assert_eq!(selection_in_range(&[0, 2, 3], 3), vec![0, 2]);
+        assert_eq!(selection_in_range(&[0, 2], 3), vec![0, 2]);
+    }
+}
@@ -0,0 +1,268 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::{nav_classes, NavVariant};
+use crate::icons::BI;
+use crate::util::{use_transition, FADE_DURATION};
+
+/// # Properties of [Tabs]
+#[derive(Properties, Clone, PartialEq)]
+pub struct TabsProps {
+    /// Panes of content, one tab is displayed for each of them
+    #[prop_or_default]
+    pub children: ChildrenWithProps<TabPane>,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// HTML id of the tabs, used as a prefix for the ids of tabs and panes.
+    /// Must be unique in the page.
+    #[prop_or(AttrValue::from("tabs"))]
+    pub id: AttrValue,
+
+    /// `event_key` of the active [TabPane]. When set, the tabs are controlled: the
+    /// application must update it from `on_select`. When unset, the tabs manage
+    /// the active pane themselves, starting with the first one.
+    #[prop_or_default]
+    pub active_key: Option<AttrValue>,
+
+    /// Called with the `event_key` of the [TabPane] selected by the user
+    #[prop_or_default]
+    pub on_select: Callback<AttrValue>,
+
+    /// Style of the tabs, default [NavVariant::Tabs]
+    #[prop_or(NavVariant::Tabs)]
+    pub variant: NavVariant,
+
+    /// Tabs take all the available width, proportionally to their content
+    #[prop_or_default]
+    pub fill: bool,
+
+    /// Tabs take all the available width, with the same width
+    #[prop_or_default]
+    pub justified: bool,
+
+    /// Tabs are stacked vertically, on the left of the panes
+    #[prop_or_default]
+    pub vertical: bool,
+
+    /// Fade between panes, default true
+    #[prop_or(true)]
+    pub fade: bool,
+}
+
+/// # Tabs component
+/// Tabbed interface switching between [TabPane] children.
+///
+/// This component is implemented with Yew instead of Bootstrap's JavaScript. The
+/// active pane can either be managed by the component, or controlled with
+/// `active_key` and `on_select`.
+///
+/// See [TabsProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{TabPane, Tabs};
+///
+/// fn test() -> Html {
+///     html!{
+///         <Tabs id="profile-tabs">
+///             <TabPane event_key="home" title="Home">{"Home content"}</TabPane>
+///             <TabPane event_key="profile" title="Profile">{"Profile content"}</TabPane>
+///             <TabPane event_key="contact" title="Contact" disabled={true}>{"Contact content"}</TabPane>
+///         </Tabs>
+///     }
+/// }
+/// ```
+///
+/// ## Controlled example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{NavVariant, TabPane, Tabs};
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let key = use_state(|| AttrValue::from("first"));
+///     let on_select = { let key = key.clone(); Callback::from(move |k| key.set(k)) };
+///
+///     html!{
+///         <Tabs active_key={(*key).clone()} {on_select} variant={NavVariant::Pills} vertical={true}>
+///             <TabPane event_key="first" title="First">{"First pane"}</TabPane>
+///             <TabPane event_key="second" title="Second">{"Second pane"}</TabPane>
+///         </Tabs>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Tabs(props: &TabsProps) -> Html {
+    let content_ref = use_node_ref();
+    let uncontrolled_key = use_state_eq(|| None::<AttrValue>);
+    let first_key = props.children.iter().next().map(|pane| pane.props.event_key.clone());
+    let requested = props
+        .active_key
+        .clone()
+        .or_else(|| (*uncontrolled_key).clone())
+        .or(first_key);
+
+    // The displayed pane is faded out before the requested one is faded in
+    let displayed = use_state_eq(|| requested.clone());
+    let stage = use_transition(
+        *displayed == requested,
+        if props.fade { FADE_DURATION } else { 0 },
+        content_ref.clone(),
+        false,
+    );
+    {
+        let displayed = displayed.clone();
+        use_effect_with((stage.is_visible(), requested.clone()), move |(visible, requested)| {
+            if !*visible {
+                displayed.set(requested.clone());
+            }
+        });
+    }
+
+    let select = {
+        let uncontrolled_key = uncontrolled_key.clone();
+        let on_select = props.on_select.clone();
+        let controlled = props.active_key.is_some();
+        Rc::new(move |key: AttrValue| {
+            if !controlled {
+                uncontrolled_key.set(Some(key.clone()));
+            }
+            on_select.emit(key);
+        })
+    };
+
+    let mut nav = nav_classes(Some(props.variant), props.fill, props.justified, props.vertical);
+    if props.vertical {
+        nav.push("me-3");
+    }
+
+    let tabs = html! {
+        <ul class={nav} role="tablist" aria-orientation={props.vertical.then_some("vertical")}>
+            {
+                for props.children.iter().map(|pane| {
+                    let pane = &pane.props;
+                    let active = requested.as_ref() == Some(&pane.event_key);
+                    let onclick = {
+                        let select = select.clone();
+                        let key = pane.event_key.clone();
+                        Callback::from(move |_: MouseEvent| select(key.clone()))
+                    };
+                    html! {
+                        <li class="nav-item" role="presentation">
+                            <button
+                                class={classes!("nav-link", active.then_some("active"))}
+                                id={format!("{}-tab-{}", props.id, pane.event_key)}
+                                type="button"
+                                role="tab"
+                                aria-controls={format!("{}-pane-{}", props.id, pane.event_key)}
+                                aria-selected={if active { "true" } else { "false" }}
+                                disabled={pane.disabled}
+                                {onclick}
+                            >
+                                if let Some(icon) = pane.icon {
+                                    {icon}{" "}
+                                }
+                                { pane.title.clone() }
+                            </button>
+                        </li>
+                    }
+                })
+            }
+        </ul>
+    };
+
+    let content = html! {
+        <div class="tab-content" ref={content_ref}>
+            {
+                for props.children.iter().map(|mut pane| {
+                    let pane_props = Rc::make_mut(&mut pane.props);
+                    let displayed = displayed.as_ref() == Some(&pane_props.event_key);
+                    pane_props.pane_id = AttrValue::from(format!("{}-pane-{}", props.id, pane_props.event_key));
+                    pane_props.tab_id = AttrValue::from(format!("{}-tab-{}", props.id, pane_props.event_key));
+                    pane_props.pane_classes = classes!(
+                        props.fade.then_some("fade"),
+                        (displayed && stage.is_visible()).then_some("active"),
+                        (displayed && stage.is_shown()).then_some("show"),
+                    );
+                    pane
+                })
+            }
+        </div>
+    };
+
+    html! {
+        if props.vertical {
+            <div class={classes!("d-flex", "align-items-start", props.class.clone())} id={props.id.clone()}>
+                { tabs }
+                { content }
+            </div>
+        } else {
+            <div class={props.class.clone()} id={props.id.clone()}>
+                { tabs }
+                { content }
+            </div>
+        }
+    }
+}
+
+/// # Properties of [TabPane]
+#[derive(Properties, Clone, PartialEq)]
+pub struct TabPaneProps {
+    /// Unique key of the pane in the parent [Tabs]
+    pub event_key: AttrValue,
+
+    /// Title of the tab
+    #[prop_or_default]
+    pub title: AttrValue,
+
+    /// Optional icon of the tab
+    #[prop_or_default]
+    pub icon: Option<&'static BI>,
+
+    /// Tab is disabled
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// Content of the pane
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// HTML id of the pane, set by the parent [Tabs]
+    #[prop_or_default]
+    pane_id: AttrValue,
+
+    /// HTML id of the tab, set by the parent [Tabs]
+    #[prop_or_default]
+    tab_id: AttrValue,
+
+    /// Classes set by the parent [Tabs] to display the pane
+    #[prop_or_default]
+    pane_classes: Classes,
+}
+
+/// # Pane of content, child of [Tabs]
+///
+/// See [TabPaneProps] for a listing of properties.
+#[function_component]
+pub fn TabPane(props: &TabPaneProps) -> Html {
+    html! {
+        <div
+            class={classes!("tab-pane", props.pane_classes.clone(), props.class.clone())}
+            id={props.pane_id.clone()}
+            role="tabpanel"
+            aria-labelledby={props.tab_id.clone()}
+            tabindex="0"
+        >
+            { for props.children.iter() }
+        </div>
+    }
+}
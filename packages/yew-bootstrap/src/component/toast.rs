@@ -0,0 +1,530 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use gloo_timers::callback::Timeout;
+use yew::prelude::*;
+
+use crate::icons::BI;
+use crate::util::{use_transition, ArrangeX, ArrangeY, Color, TransitionStage, FADE_DURATION};
+
+/// # Context of a [Toast]
+///
+/// Provided by a [Toast] to its children, so that [ToastHeader] or custom
+/// components can request the toast to be closed.
+#[derive(Clone, PartialEq)]
+pub struct ToastContext {
+    /// Request the toast to be closed, calls `on_close` on the [Toast]
+    pub close: Callback<()>,
+}
+
+/// # Properties of [Toast]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ToastProps {
+    /// Inner components, typically [ToastHeader] and [ToastBody]
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Toast is shown, default true
+    #[prop_or(true)]
+    pub show: bool,
+
+    /// Called when the toast requests to be closed, either by its close button or
+    /// when `autohide` delay expires. The application is responsible for setting
+    /// `show` to false.
+    #[prop_or_default]
+    pub on_close: Callback<()>,
+
+    /// Called when the toast is hidden, after the end of the transition
+    #[prop_or_default]
+    pub on_hidden: Callback<()>,
+
+    /// Automatically close the toast after `delay`, default true
+    #[prop_or(true)]
+    pub autohide: bool,
+
+    /// Delay in milliseconds before closing the toast when `autohide` is set,
+    /// default 5000. The delay is suspended while the toast is hovered or focused.
+    #[prop_or(5000)]
+    pub delay: u32,
+
+    /// Use fade transition when showing or hiding the toast, default true
+    #[prop_or(true)]
+    pub fade: bool,
+
+    /// Optional background color of the toast
+    #[prop_or_default]
+    pub style: Option<Color>,
+}
+
+/// # Toast component
+/// Lightweight notification, parent of [ToastHeader] and [ToastBody]. Toasts are
+/// typically grouped in a [ToastContainer], or managed by a [ToastProvider].
+///
+/// The toast is shown and hidden by Yew: Bootstrap's JavaScript is not needed.
+///
+/// See [ToastProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{Toast, ToastBody, ToastContainer, ToastHeader};
+/// use yew_bootstrap::util::{ArrangeX, ArrangeY};
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let show = use_state(|| true);
+///     let on_close = { let show = show.clone(); Callback::from(move |_| show.set(false)) };
+///
+///     html!{
+///         <ToastContainer position={(ArrangeX::End0, ArrangeY::Top0)} fixed=true class="p-3">
+///             <Toast show={*show} {on_close}>
+///                 <ToastHeader title="Yew Bootstrap">
+///                     <small>{"just now"}</small>
+///                 </ToastHeader>
+///                 <ToastBody>{"Hello, world! This is a toast message."}</ToastBody>
+///             </Toast>
+///         </ToastContainer>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn Toast(props: &ToastProps) -> Html {
+    let toast_ref = use_node_ref();
+    let duration = if props.fade { FADE_DURATION } else { 0 };
+    let stage = use_transition(props.show, duration, toast_ref.clone(), true);
+
+    let hovered = use_state_eq(|| false);
+    let focused = use_state_eq(|| false);
+
+    // Keep the latest callback, so that the timer is not restarted on each render
+    let on_close = use_mut_ref(|| props.on_close.clone());
+    *on_close.borrow_mut() = props.on_close.clone();
+
+    use_effect_with(
+        (stage == TransitionStage::Shown, props.autohide && !*hovered && !*focused, props.delay),
+        move |(shown, autohide, delay)| {
+            let timeout = (*shown && *autohide).then(|| {
+                Timeout::new(*delay, move || on_close.borrow().emit(()))
+            });
+            move || drop(timeout)
+        },
+    );
+
+    // Report the end of the hiding transition
+    {
+        let was_visible = use_mut_ref(|| false);
+        let on_hidden = props.on_hidden.clone();
+        use_effect_with(stage.is_visible(), move |visible| {
+            if *visible {
+                *was_visible.borrow_mut() = true;
+            } else if was_visible.replace(false) {
+                on_hidden.emit(());
+            }
+        });
+    }
+
+    let mut classes = classes!("toast", props.class.clone());
+    if props.fade {
+        classes.push("fade");
+    }
+    if stage.is_visible() {
+        classes.push("show");
+        if !stage.is_shown() {
+            classes.push("showing");
+        }
+    }
+    if let Some(color) = &props.style {
+        classes.push(format!("bg-{color}"));
+        classes.push("border-0");
+        if [Color::Warning, Color::Info, Color::Light].contains(color) {
+            classes.push("text-dark");
+        } else {
+            classes.push("text-white");
+        }
+    }
+
+    let context = ToastContext { close: props.on_close.clone() };
+
+    let set_hovered = |value: bool| {
+        let hovered = hovered.clone();
+        Callback::from(move |_: MouseEvent| hovered.set(value))
+    };
+    let set_focused = |value: bool| {
+        let focused = focused.clone();
+        Callback::from(move |_: FocusEvent| focused.set(value))
+    };
+
+    html! {
+        <div
+            ref={toast_ref}
+            class={classes}
+            role="alert"
+            aria-live="assertive"
+            aria-atomic="true"
+            onmouseenter={set_hovered(true)}
+            onmouseleave={set_hovered(false)}
+            onfocusin={set_focused(true)}
+            onfocusout={set_focused(false)}
+        >
+            <ContextProvider<ToastContext> {context}>
+                { for props.children.iter() }
+            </ContextProvider<ToastContext>>
+        </div>
+    }
+}
+
+/// # Properties of [ToastHeader]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ToastHeaderProps {
+    /// Title of the toast
+    #[prop_or_default]
+    pub title: AttrValue,
+
+    /// Inner components, displayed after the title
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Optional icon, displayed before the title
+    #[prop_or_default]
+    pub icon: Option<&'static BI>,
+
+    /// Show a button to close the [Toast], default true
+    #[prop_or(true)]
+    pub close_button: bool,
+}
+
+/// # Header of a [Toast]
+///
+/// See [ToastHeaderProps] for a listing of properties.
+#[function_component]
+pub fn ToastHeader(props: &ToastHeaderProps) -> Html {
+    let context = use_context::<ToastContext>();
+
+    html! {
+        <div class={classes!("toast-header", props.class.clone())}>
+            if let Some(icon) = props.icon {
+                <span class="me-2">{icon}</span>
+            }
+            <strong class="me-auto">{ props.title.clone() }</strong>
+            { for props.children.iter() }
+            if props.close_button {
+                <button
+                    type="button"
+                    class="btn-close"
+                    aria-label="Close"
+                    onclick={context.map(|context| context.close.reform(|_| ()))}
+                ></button>
+            }
+        </div>
+    }
+}
+
+/// # Properties of [ToastBody]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ToastBodyProps {
+    /// Inner components
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// # Body of a [Toast]
+///
+/// See [ToastBodyProps] for a listing of properties.
+#[function_component]
+pub fn ToastBody(props: &ToastBodyProps) -> Html {
+    html! {
+        <div class={classes!("toast-body", props.class.clone())}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// # Properties of [ToastContainer]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ToastContainerProps {
+    /// Inner toasts
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Extra CSS classes to include, in addition to the defaults.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Position of the container. If None, the container is placed in the normal
+    /// flow of the page.
+    #[prop_or_default]
+    pub position: Option<(ArrangeX, ArrangeY)>,
+
+    /// Position relative to the viewport instead of the closest positioned parent
+    #[prop_or_default]
+    pub fixed: bool,
+}
+
+/// # Container of [Toast] components
+///
+/// Stacks toasts vertically, optionally at a given position.
+///
+/// See [ToastContainerProps] for a listing of properties.
+#[function_component]
+pub fn ToastContainer(props: &ToastContainerProps) -> Html {
+    let mut classes = classes!("toast-container", props.class.clone());
+    if let Some((x, y)) = &props.position {
+        classes.push(if props.fixed { "position-fixed" } else { "position-absolute" });
+        classes.push(x.to_string());
+        classes.push(y.to_string());
+        match (x == &ArrangeX::Start50, y == &ArrangeY::Top50) {
+            (true, true) => classes.push("translate-middle"),
+            (true, false) => classes.push("translate-middle-x"),
+            (false, true) => classes.push("translate-middle-y"),
+            (false, false) => (),
+        }
+    }
+
+    html! {
+        <div class={classes} style={props.fixed.then_some("z-index: 1090;")}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// # Toast displayed by a [ToastProvider]
+///
+/// Used with [Toaster::push], typically created with `..ToastOptions::default()`.
+#[derive(Clone, PartialEq)]
+pub struct ToastOptions {
+    /// Optional title. If set, the toast has a [ToastHeader].
+    pub title: Option<AttrValue>,
+    /// Content of the toast
+    pub body: Html,
+    /// Optional background color
+    pub color: Option<Color>,
+    /// Optional icon displayed before the title
+    pub icon: Option<&'static BI>,
+    /// Delay in milliseconds before hiding the toast, or None to keep it until
+    /// dismissed. Default 5000.
+    pub delay: Option<u32>,
+    /// Show a close button, default true
+    pub dismissible: bool,
+}
+
+impl Default for ToastOptions {
+    fn default() -> Self {
+        ToastOptions {
+            title: None,
+            body: Html::default(),
+            color: None,
+            icon: None,
+            delay: Some(5000),
+            dismissible: true,
+        }
+    }
+}
+
+/// Identifier of a toast pushed to a [Toaster]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(usize);
+
+/// Toast managed by a [ToastProvider]
+#[derive(Clone, PartialEq)]
+struct ToastEntry {
+    id: ToastId,
+    options: ToastOptions,
+    show: bool,
+}
+
+/// Stack of toasts managed by a [ToastProvider]
+#[derive(Clone, Default, PartialEq)]
+struct ToastStack {
+    toasts: Vec<ToastEntry>,
+}
+
+enum ToastAction {
+    Push(ToastId, ToastOptions),
+    Hide(ToastId),
+    HideAll,
+    Remove(ToastId),
+}
+
+impl Reducible for ToastStack {
+    type Action = ToastAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut toasts = self.toasts.clone();
+        match action {
+            ToastAction::Push(id, options) => toasts.push(ToastEntry { id, options, show: true }),
+            ToastAction::Hide(id) => toasts
+                .iter_mut()
+                .filter(|entry| entry.id == id)
+                .for_each(|entry| entry.show = false),
+            ToastAction::HideAll => toasts.iter_mut().for_each(|entry| entry.show = false),
+            ToastAction::Remove(id) => toasts.retain(|entry| entry.id != id),
+        }
+        Rc::new(ToastStack { toasts })
+    }
+}
+
+/// # Handle to push toasts to the closest [ToastProvider]
+///
+/// Obtained with the [use_toast] hook.
+#[derive(Clone, PartialEq)]
+pub struct Toaster {
+    dispatcher: UseReducerDispatcher<ToastStack>,
+    next_id: Rc<Cell<usize>>,
+}
+
+impl Toaster {
+    /// Show a new toast, returning its identifier
+    pub fn push(&self, options: ToastOptions) -> ToastId {
+        let id = ToastId(self.next_id.replace(self.next_id.get() + 1));
+        self.dispatcher.dispatch(ToastAction::Push(id, options));
+        id
+    }
+
+    /// Hide a toast previously pushed
+    pub fn hide(&self, id: ToastId) {
+        self.dispatcher.dispatch(ToastAction::Hide(id));
+    }
+
+    /// Hide all toasts
+    pub fn clear(&self) {
+        self.dispatcher.dispatch(ToastAction::HideAll);
+    }
+}
+
+/// # Properties of [ToastProvider]
+#[derive(Properties, Clone, PartialEq)]
+pub struct ToastProviderProps {
+    /// Inner components, which can use [use_toast]
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Position of the toasts in the viewport, default bottom right
+    #[prop_or((ArrangeX::End0, ArrangeY::Bottom0))]
+    pub position: (ArrangeX, ArrangeY),
+}
+
+/// # Toast provider
+///
+/// Manages a stack of [Toast] components, displayed in a [ToastContainer] fixed
+/// in the viewport. Any component inside the provider can push a toast using
+/// the [use_toast] hook.
+///
+/// See [ToastProviderProps] for a listing of properties.
+///
+/// ## Example
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_bootstrap::component::{use_toast, Button, ToastOptions, ToastProvider};
+/// use yew_bootstrap::icons::BI;
+/// use yew_bootstrap::util::Color;
+///
+/// #[function_component]
+/// fn SaveButton() -> Html {
+///     let toaster = use_toast();
+///     let onclick = Callback::from(move |_| {
+///         toaster.push(ToastOptions {
+///             title: Some("Saved".into()),
+///             body: html! { "Your changes have been saved." },
+///             color: Some(Color::Success),
+///             icon: Some(&BI::CHECK_CIRCLE),
+///             ..ToastOptions::default()
+///         });
+///     });
+///
+///     html! { <Button {onclick}>{ "Save" }</Button> }
+/// }
+///
+/// fn test() -> Html {
+///     html! {
+///         <ToastProvider>
+///             <SaveButton />
+///         </ToastProvider>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn ToastProvider(props: &ToastProviderProps) -> Html {
+    let stack = use_reducer(ToastStack::default);
+    let next_id = use_memo((), |_| Rc::new(Cell::new(0)));
+
+    let toaster = Toaster {
+        dispatcher: stack.dispatcher(),
+        next_id: (*next_id).clone(),
+    };
+
+    let toasts = stack.toasts.iter().map(|entry| {
+        let id = entry.id;
+        let options = &entry.options;
+        let on_close = toaster.dispatcher.to_owned();
+        let on_close = Callback::from(move |_| on_close.dispatch(ToastAction::Hide(id)));
+        let on_hidden = toaster.dispatcher.to_owned();
+        let on_hidden = Callback::from(move |_| on_hidden.dispatch(ToastAction::Remove(id)));
+
+        let close_white = options.color.as_ref()
+            .is_some_and(|color| ![Color::Warning, Color::Info, Color::Light].contains(color));
+
+        html! {
+            <Toast
+                key={id.0}
+                show={entry.show}
+                on_close={on_close.clone()}
+                {on_hidden}
+                autohide={options.delay.is_some()}
+                delay={options.delay.unwrap_or_default()}
+                style={options.color.clone()}
+            >
+                if options.title.is_some() || options.icon.is_some() {
+                    <ToastHeader
+                        title={options.title.clone().unwrap_or_default()}
+                        icon={options.icon}
+                        close_button={options.dismissible}
+                    />
+                    <ToastBody>{ options.body.clone() }</ToastBody>
+                } else {
+                    <div class="d-flex">
+                        <ToastBody>{ options.body.clone() }</ToastBody>
+                        if options.dismissible {
+                            <button
+                                type="button"
+                                class={classes!("btn-close", "me-2", "m-auto", close_white.then_some("btn-close-white"))}
+                                aria-label="Close"
+                                onclick={on_close.reform(|_| ())}
+                            ></button>
+                        }
+                    </div>
+                }
+            </Toast>
+        }
+    }).collect::<Html>();
+
+    html! {
+        <ContextProvider<Toaster> context={toaster.clone()}>
+            { for props.children.iter() }
+            <ToastContainer position={Some(props.position.clone())} fixed=true class="p-3">
+                { toasts }
+            </ToastContainer>
+        </ContextProvider<Toaster>>
+    }
+}
+
+/// # Toast hook
+///
+/// Returns the [Toaster] of the closest [ToastProvider], to push new toasts.
+///
+/// Panics if the component is not inside a [ToastProvider].
+#[hook]
+pub fn use_toast() -> Toaster {
+    use_context::<Toaster>().expect("use_toast must be used inside a ToastProvider")
+}
@@ -197,7 +197,7 @@ impl IntoPropValue<TooltipFocusTrigger> for bool {
 }
 
 impl TooltipFocusTrigger {
-    fn media_queries(&self) -> Option<MediaQueryList> {
+    pub(crate) fn media_queries(&self) -> Option<MediaQueryList> {
         let query = match self {
             Self::Always | Self::Never => return None,
             Self::IfHoverNone => MEDIA_QUERY_HOVER_NONE,
@@ -208,7 +208,7 @@ impl TooltipFocusTrigger {
         w.match_media(query).ok().flatten()
     }
 
-    fn should_trigger(&self) -> bool {
+    pub(crate) fn should_trigger(&self) -> bool {
         let Some(queries) = self.media_queries() else {
             return match self {
                 Self::Always => true,
@@ -453,73 +453,12 @@ pub fn Tooltip(props: &TooltipProps) -> Html {
             distance: 6,
         })],
         strategy: Strategy::Fixed,
-        ..Default::default()
     });
 
     let popper = use_popper(props.target.clone(), tooltip_ref.clone(), options).unwrap();
 
-    let focused = use_state_eq(|| false);
-    let focus_should_trigger = use_state_eq(|| props.trigger_on_focus.should_trigger());
-    let hovered = use_state_eq(|| false);
-
-    let onshow = {
-        let focused = focused.clone();
-        let hovered = hovered.clone();
-        Callback::from(move |evt_type: String| match evt_type.as_str() {
-            "mouseenter" => hovered.set(true),
-            "focusin" => focused.set(true),
-            _ => {}
-        })
-    };
-
-    let onhide = {
-        let focused = focused.clone();
-        let hovered = hovered.clone();
-        Callback::from(move |evt_type: String| match evt_type.as_str() {
-            "mouseleave" => hovered.set(false),
-            "focusout" => focused.set(false),
-            _ => {}
-        })
-    };
-
-    let focus_should_trigger_listener = {
-        let focus_should_trigger = focus_should_trigger.clone();
-
-        Callback::from(move |v: bool| {
-            focus_should_trigger.set(v);
-        })
-    };
-
-    use_effect_with(props.trigger_on_focus, |trigger_on_focus| {
-        let r = if let Some(media_query_list) = trigger_on_focus.media_queries() {
-            let media_query_list_listener = Closure::<dyn Fn(MediaQueryListEvent)>::wrap(Box::new(
-                move |e: MediaQueryListEvent| {
-                    focus_should_trigger_listener.emit(e.matches());
-                },
-            ));
-
-            let _ = media_query_list.add_event_listener_with_callback(
-                "change",
-                media_query_list_listener.as_ref().unchecked_ref(),
-            );
-
-            Some((media_query_list_listener, media_query_list))
-        } else {
-            // Current trigger_on_focus rule doesn't need a MediaQueryList change event listener.
-            None
-        };
-
-        move || {
-            if let Some((media_query_list_listener, media_query_list)) = r {
-                let _ = media_query_list.remove_event_listener_with_callback(
-                    "change",
-                    media_query_list_listener.as_ref().unchecked_ref(),
-                );
-
-                drop(media_query_list_listener);
-            }
-        }
-    });
+    let (focused, hovered) = use_focus_hover(props.target.clone());
+    let focus_should_trigger = use_focus_should_trigger(props.trigger_on_focus);
 
     if props.disabled {
         // Whenever this component is disabled, explicitly set our focus and
@@ -530,7 +469,7 @@ pub fn Tooltip(props: &TooltipProps) -> Html {
 
     let show = !props.disabled
         && (props.show
-            || (*focused && *focus_should_trigger)
+            || (*focused && focus_should_trigger)
             || (*hovered && props.trigger_on_hover));
     let data_show = show.then(AttrValue::default);
 
@@ -551,9 +490,78 @@ pub fn Tooltip(props: &TooltipProps) -> Html {
         },
     );
 
+    use_described_by(props.target.clone(), props.id.clone(), show);
+
+    let mut class = classes!["tooltip", "bs-tooltip-auto"];
+    if props.fade {
+        class.push("fade");
+    }
+    if show {
+        class.push("show");
+    }
+
+    let mut popper_style = popper.state.styles.popper.clone();
+    // Make sure `<Tooltip>` doesn't interfere with events going to other
+    // elements, even when hidden.
+    popper_style.insert("pointer-events".to_string(), "none".to_string());
+
+    create_portal(
+        html_nested! {
+            <div
+                ref={&tooltip_ref}
+                role="tooltip"
+                {class}
+                style={&popper_style}
+                data-show={&data_show}
+                id={props.id.clone()}
+            >
+                <div
+                    class="tooltip-arrow"
+                    data-popper-arrow="true"
+                    style={&popper.state.styles.arrow}
+                />
+                <div class="tooltip-inner">
+                    { for props.children.iter() }
+                </div>
+            </div>
+        },
+        gloo_utils::body().into(),
+    )
+}
+
+/// # Focus and hover state hook
+///
+/// Returns whether the `target` element currently has focus, and whether it is
+/// hovered by the mouse cursor. Shared by [Tooltip] and
+/// [crate::component::Popover].
+#[hook]
+pub(crate) fn use_focus_hover(target: NodeRef) -> (UseStateHandle<bool>, UseStateHandle<bool>) {
+    let focused = use_state_eq(|| false);
+    let hovered = use_state_eq(|| false);
+
+    let onshow = {
+        let focused = focused.clone();
+        let hovered = hovered.clone();
+        Callback::from(move |evt_type: String| match evt_type.as_str() {
+            "mouseenter" => hovered.set(true),
+            "focusin" => focused.set(true),
+            _ => {}
+        })
+    };
+
+    let onhide = {
+        let focused = focused.clone();
+        let hovered = hovered.clone();
+        Callback::from(move |evt_type: String| match evt_type.as_str() {
+            "mouseleave" => hovered.set(false),
+            "focusout" => focused.set(false),
+            _ => {}
+        })
+    };
+
     // Attach event handlers. These are always wired up, just we ignore the
     // result when they're disabled.
-    use_effect_with(props.target.clone(), |target_ref| {
+    use_effect_with(target, |target_ref| {
         let show_listener = Closure::<dyn Fn(Event)>::wrap(Box::new(move |e: Event| {
             onshow.emit(e.type_());
         }));
@@ -606,57 +614,77 @@ pub fn Tooltip(props: &TooltipProps) -> Html {
         }
     });
 
-    use_effect_with(
-        (props.target.clone(), props.id.clone(), show),
-        |(target_ref, tooltip_id, show)| {
-            let Some(target_elem) = target_ref.cast::<HtmlElement>() else {
-                return;
-            };
+    (focused, hovered)
+}
+
+/// # Focus trigger hook
+///
+/// Returns whether focusing the target should trigger the overlay, following
+/// the media queries of `trigger_on_focus`, see [TooltipFocusTrigger].
+#[hook]
+pub(crate) fn use_focus_should_trigger(trigger_on_focus: TooltipFocusTrigger) -> bool {
+    let focus_should_trigger = use_state_eq(|| trigger_on_focus.should_trigger());
+
+    let focus_should_trigger_listener = {
+        let focus_should_trigger = focus_should_trigger.clone();
+
+        Callback::from(move |v: bool| {
+            focus_should_trigger.set(v);
+        })
+    };
+
+    use_effect_with(trigger_on_focus, |trigger_on_focus| {
+        let r = if let Some(media_query_list) = trigger_on_focus.media_queries() {
+            let media_query_list_listener = Closure::<dyn Fn(MediaQueryListEvent)>::wrap(Box::new(
+                move |e: MediaQueryListEvent| {
+                    focus_should_trigger_listener.emit(e.matches());
+                },
+            ));
+
+            let _ = media_query_list.add_event_listener_with_callback(
+                "change",
+                media_query_list_listener.as_ref().unchecked_ref(),
+            );
+
+            Some((media_query_list_listener, media_query_list))
+        } else {
+            // Current trigger_on_focus rule doesn't need a MediaQueryList change event listener.
+            None
+        };
+
+        move || {
+            if let Some((media_query_list_listener, media_query_list)) = r {
+                let _ = media_query_list.remove_event_listener_with_callback(
+                    "change",
+                    media_query_list_listener.as_ref().unchecked_ref(),
+                );
 
-            match (tooltip_id, show) {
-                (Some(tooltip_id), true) => {
-                    let _ = target_elem.set_attribute("aria-describedby", tooltip_id);
-                }
-                _ => {
-                    let _ = target_elem.remove_attribute("aria-describedby");
-                }
+                drop(media_query_list_listener);
             }
-        },
-    );
+        }
+    });
 
-    let mut class = classes!["tooltip", "bs-tooltip-auto"];
-    if props.fade {
-        class.push("fade");
-    }
-    if show {
-        class.push("show");
-    }
+    *focus_should_trigger
+}
 
-    let mut popper_style = popper.state.styles.popper.clone();
-    // Make sure `<Tooltip>` doesn't interfere with events going to other
-    // elements, even when hidden.
-    popper_style.insert("pointer-events".to_string(), "none".to_string());
+/// # Description hook
+///
+/// Sets the `aria-describedby` attribute of `target` to `id` while the overlay
+/// is shown.
+#[hook]
+pub(crate) fn use_described_by(target: NodeRef, id: Option<AttrValue>, show: bool) {
+    use_effect_with((target, id, show), |(target_ref, tooltip_id, show)| {
+        let Some(target_elem) = target_ref.cast::<HtmlElement>() else {
+            return;
+        };
 
-    create_portal(
-        html_nested! {
-            <div
-                ref={&tooltip_ref}
-                role="tooltip"
-                {class}
-                style={&popper_style}
-                data-show={&data_show}
-                id={props.id.clone()}
-            >
-                <div
-                    class="tooltip-arrow"
-                    data-popper-arrow="true"
-                    style={&popper.state.styles.arrow}
-                />
-                <div class="tooltip-inner">
-                    { for props.children.iter() }
-                </div>
-            </div>
-        },
-        gloo_utils::body().into(),
-    )
+        match (tooltip_id, show) {
+            (Some(tooltip_id), true) => {
+                let _ = target_elem.set_attribute("aria-describedby", tooltip_id);
+            }
+            _ => {
+                let _ = target_elem.remove_attribute("aria-describedby");
+            }
+        }
+    });
 }
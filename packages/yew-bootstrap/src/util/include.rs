@@ -45,3 +45,35 @@ pub fn include_inline() -> VNode {
 pub fn include_cdn_icons() -> VNode {
     BIFiles::cdn()
 }
+
+/// Style of the [crate::component::form::RangeSlider]: both ranges are stacked on a
+/// common track, only their thumbs receiving the pointer. Colors follow the theme,
+/// with Bootstrap 5.1 fallbacks.
+pub(crate) const RANGE_SLIDER_STYLE: &str = "
+.range-slider { position: relative; height: 1.5rem; }
+.range-slider .range-slider-track, .range-slider .range-slider-fill {
+    position: absolute; top: 0.5rem; height: 0.5rem; border-radius: 1rem; pointer-events: none;
+}
+.range-slider .range-slider-track { left: 0; right: 0; background-color: var(--bs-secondary-bg, #e9ecef); }
+.range-slider .range-slider-fill { background-color: var(--bs-primary, #0d6efd); }
+.range-slider.disabled .range-slider-fill { background-color: var(--bs-gray-500, #adb5bd); }
+.range-slider .form-range { position: absolute; top: 0; left: 0; pointer-events: none; }
+.range-slider .form-range::-webkit-slider-runnable-track { background: transparent; }
+.range-slider .form-range::-moz-range-track { background: transparent; }
+.range-slider .form-range::-webkit-slider-thumb { pointer-events: all; }
+.range-slider .form-range::-moz-range-thumb { pointer-events: all; }
+";
+
+/// Add a `<style>` element with the given `id` and `css` to the head of the page,
+/// unless it already exists, so that the style of a component is only included once
+pub(crate) fn include_style_once(id: &str, css: &str) {
+    let document = gloo_utils::document();
+    if document.get_element_by_id(id).is_some() {
+        return;
+    }
+    if let Ok(style) = document.create_element("style") {
+        style.set_id(id);
+        style.set_text_content(Some(css));
+        let _ = gloo_utils::head().append_child(&style);
+    }
+}
@@ -4,6 +4,8 @@ mod size;
 mod dimension;
 mod position;
 mod arrange;
+mod scroll_lock;
+mod transition;
 
 pub use self::color::*;
 pub use self::include::*;
@@ -11,3 +13,5 @@ pub use self::size::*;
 pub use self::dimension::*;
 pub use self::position::*;
 pub use self::arrange::*;
+pub(crate) use self::scroll_lock::*;
+pub(crate) use self::transition::*;
@@ -0,0 +1,55 @@
+use std::cell::Cell;
+use yew::prelude::*;
+
+thread_local! {
+    /// Number of components currently locking the scroll of the body
+    static LOCKS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// # Lock of the scrolling of the page body
+///
+/// Bootstrap prevents the page from scrolling while a modal dialog or an
+/// offcanvas is shown. The body is locked as long as at least one instance of
+/// this structure exists, and the right padding compensates for the hidden
+/// scrollbar so that the content does not move.
+pub(crate) struct BodyScrollLock {}
+
+impl BodyScrollLock {
+    pub(crate) fn new() -> Self {
+        if LOCKS.with(|locks| locks.replace(locks.get() + 1)) == 0 {
+            let window = gloo_utils::window();
+            let inner_width = window.inner_width().ok().and_then(|w| w.as_f64()).unwrap_or_default();
+            let client_width = gloo_utils::document_element().client_width() as f64;
+            let scrollbar_width = inner_width - client_width;
+
+            let style = gloo_utils::body().style();
+            let _ = style.set_property("overflow", "hidden");
+            if scrollbar_width > 0.0 {
+                let _ = style.set_property("padding-right", &format!("{scrollbar_width}px"));
+            }
+        }
+        Self {}
+    }
+}
+
+impl Drop for BodyScrollLock {
+    fn drop(&mut self) {
+        if LOCKS.with(|locks| locks.replace(locks.get() - 1)) == 1 {
+            let style = gloo_utils::body().style();
+            let _ = style.remove_property("overflow");
+            let _ = style.remove_property("padding-right");
+        }
+    }
+}
+
+/// # Body scroll lock hook
+///
+/// Prevent the page body from scrolling as long as `locked` is true. See
+/// [BodyScrollLock].
+#[hook]
+pub(crate) fn use_body_scroll_lock(locked: bool) {
+    use_effect_with(locked, |locked| {
+        let lock = locked.then(BodyScrollLock::new);
+        move || drop(lock)
+    });
+}
@@ -0,0 +1,128 @@
+use gloo_timers::callback::Timeout;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+/// Duration of Bootstrap's `fade` transition on most components, in milliseconds
+pub(crate) const FADE_DURATION: u32 = 150;
+
+/// Duration of the `fade` transition for [crate::component::Modal] and the `transform`
+/// transition of [crate::component::Offcanvas], in milliseconds
+pub(crate) const MODAL_DURATION: u32 = 300;
+
+/// Duration of the `height` or `width` transition of [crate::component::Collapse],
+/// in milliseconds
+pub(crate) const COLLAPSE_DURATION: u32 = 350;
+
+/// # Stage of a CSS transition driven by [use_transition]
+///
+/// Bootstrap animates components by toggling classes (eg. `show`) on elements
+/// that are already laid out. This reproduces the stages of Bootstrap's own
+/// JavaScript plugins, so they can be rendered by Yew.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TransitionStage {
+    /// Element is hidden
+    Hidden,
+    /// Element is rendered in its initial state, so the browser can lay it out
+    /// before transitioning
+    Entering,
+    /// Element is transitioning to its shown state
+    Showing,
+    /// Element is shown, no transition in progress
+    Shown,
+    /// Element is still rendered in its shown state, so the browser can lay it
+    /// out before transitioning
+    Exiting,
+    /// Element is transitioning to its hidden state
+    Hiding,
+}
+
+impl TransitionStage {
+    /// Element must be part of the layout (ie. not `display: none`)
+    pub(crate) fn is_visible(self) -> bool {
+        self != Self::Hidden
+    }
+
+    /// Element should be rendered with its `show` class
+    pub(crate) fn is_shown(self) -> bool {
+        matches!(self, Self::Showing | Self::Shown | Self::Exiting)
+    }
+}
+
+/// # Transition hook
+///
+/// Returns the current [TransitionStage] of an element which is shown when
+/// `show` is true, with a transition lasting `duration` milliseconds.
+///
+/// `node_ref` is the element being animated. It is used to force the browser to
+/// compute the layout before a transition starts, otherwise the initial state
+/// would be skipped. When `duration` is 0, there are no intermediate stages.
+///
+/// If `appear` is true and `show` is initially true, the transition is also
+/// played when the component is mounted.
+#[hook]
+pub(crate) fn use_transition(
+    show: bool,
+    duration: u32,
+    node_ref: NodeRef,
+    appear: bool,
+) -> TransitionStage {
+    let stage = use_state_eq(|| {
+        if show && !appear {
+            TransitionStage::Shown
+        } else {
+            TransitionStage::Hidden
+        }
+    });
+
+    // Start a new transition each time `show` changes
+    {
+        let stage = stage.clone();
+        use_effect_with(show, move |show| {
+            let next = match (*show, *stage) {
+                (true, _) if duration == 0 => TransitionStage::Shown,
+                (false, _) if duration == 0 => TransitionStage::Hidden,
+                (true, TransitionStage::Hidden | TransitionStage::Exiting | TransitionStage::Hiding) => {
+                    TransitionStage::Entering
+                }
+                (false, TransitionStage::Entering | TransitionStage::Showing | TransitionStage::Shown) => {
+                    TransitionStage::Exiting
+                }
+                (_, current) => current,
+            };
+            stage.set(next);
+        });
+    }
+
+    // Move to the next stage once the layout is computed or the transition ended
+    {
+        let stage = stage.clone();
+        use_effect_with(*stage, move |current| {
+            let timeout = match current {
+                TransitionStage::Entering | TransitionStage::Exiting => {
+                    if let Some(element) = node_ref.cast::<HtmlElement>() {
+                        // Reading the size forces a reflow
+                        let _ = element.offset_height();
+                    }
+                    stage.set(if *current == TransitionStage::Entering {
+                        TransitionStage::Showing
+                    } else {
+                        TransitionStage::Hiding
+                    });
+                    None
+                }
+                TransitionStage::Showing => Some(Timeout::new(duration, move || {
+                    stage.set(TransitionStage::Shown)
+                })),
+                TransitionStage::Hiding => Some(Timeout::new(duration, move || {
+                    stage.set(TransitionStage::Hidden)
+                })),
+                TransitionStage::Hidden | TransitionStage::Shown => None,
+            };
+
+            // Dropping the timeout cancels it if another transition starts
+            move || drop(timeout)
+        });
+    }
+
+    *stage
+}